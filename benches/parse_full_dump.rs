@@ -0,0 +1,98 @@
+//! Compares the buffered and `stream-parse` readers on a synthetic dump the
+//! size of the full card database, served by a local mock server.
+//!
+//! The peak RSS is per process, so run it once with and once without the
+//! feature:
+//!
+//! ```text
+//! cargo bench --bench parse_full_dump
+//! cargo bench --bench parse_full_dump --features stream-parse
+//! ```
+//!
+//! The peak RSS is read from `/proc/self/status` and so is only reported on
+//! Linux.
+
+use std::time::Instant;
+
+use trent::{client::Client, request::Request};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+const CARDS: u64 = 13_000;
+
+/// The process's peak resident set size, in kB.
+fn peak_rss_kb() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// A `{"data": [...]}` body of `CARDS` Normal Monsters with descriptions
+/// about as long as real ones.
+///
+/// The body is written one card at a time, so that no freed memory is left
+/// for the buffered response to reuse without raising the peak.
+fn dump() -> String {
+    let desc =
+        "A monster with a description long enough to match the size of a real one. ".repeat(5);
+    let mut body = String::from(r#"{"data":["#);
+
+    for id in 1..=CARDS {
+        if id > 1 {
+            body.push(',');
+        }
+        let card = serde_json::json!({
+            "id": id,
+            "name": "Monster",
+            "type": "Normal Monster",
+            "humanReadableCardType": "Normal Monster",
+            "frameType": "normal",
+            "desc": desc,
+            "race": "Dragon",
+            "attribute": "LIGHT",
+            "level": 8,
+            "atk": 3000,
+            "def": 2500,
+            "ygoprodeck_url": "",
+            "card_images": []
+        });
+        body.push_str(&card.to_string());
+    }
+    body.push_str("]}");
+
+    body
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let server = MockServer::start().await;
+    let body = dump();
+    let body_len = body.len();
+    Mock::given(method("GET"))
+        .and(path("/cardinfo.php"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(&server)
+        .await;
+
+    let client = Client::with_base_url(server.uri());
+    let before = peak_rss_kb();
+    let start = Instant::now();
+    let cards = client
+        .get(Request::all_cards())
+        .await
+        .expect("the mock dump is valid");
+    let elapsed = start.elapsed();
+    assert_eq!(cards.len() as u64, CARDS);
+
+    let rss = |kb: Option<u64>| kb.map_or("n/a".to_string(), |kb| format!("{kb} kB"));
+    println!(
+        "stream-parse: {}, body: {body_len} bytes, parsed in {elapsed:?}, \
+         peak RSS before: {}, after: {}",
+        cfg!(feature = "stream-parse"),
+        rss(before),
+        rss(peak_rss_kb())
+    );
+}
@@ -0,0 +1,129 @@
+//! A synchronous client, for callers that don't run an async runtime.
+//!
+//! Requires the `blocking` feature. It mirrors [`crate::client::Client::get`]
+//! and [`crate::client::Client::get_by_name`], building its queries with the
+//! same [`Request`] and reporting the same [`Error`]s.
+
+use crate::{
+    card::Card,
+    client::{
+        ApiResponse, DEFAULT_BASE_URL, Error, error_from_body, non_json_error, normalize_base_url,
+        rate_limited,
+    },
+    request::{Request, RequestBuilder},
+};
+
+#[derive(Debug)]
+pub struct Client {
+    client: reqwest::blocking::Client,
+    base_url: String,
+}
+
+impl Client {
+    pub fn new() -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL)
+    }
+
+    /// Creates a client that sends its requests to `base` instead of the
+    /// production API, see [`crate::client::Client::with_base_url`].
+    pub fn with_base_url(base: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            base_url: normalize_base_url(base.into()),
+        }
+    }
+
+    pub fn get(&self, request: Request<'_>) -> Result<Vec<Card>, Error> {
+        request.validate()?;
+
+        let mut builder = self.client.get(format!(
+            "{}/cardinfo.php?{}",
+            self.base_url,
+            request.to_url_params()
+        ));
+        if let Some(timeout) = request.timeout() {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = check_status(builder.send().map_err(Error::Network)?)?;
+        let status = response.status().as_u16();
+        let json = parse_json(response)?;
+        let mut cards = json.into_cards(status)?;
+        cards.retain(|c| request.post_filter(c));
+
+        Ok(cards)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Result<Card, Error> {
+        let request = RequestBuilder::new().with_name(name).build();
+
+        self.get(request)?.into_iter().next().ok_or(Error::NotFound)
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads a response body and deserializes it, see the async client's
+/// `parse_json`.
+#[cfg(not(feature = "stream-parse"))]
+fn parse_json(response: reqwest::blocking::Response) -> Result<ApiResponse, Error> {
+    let bytes = response.bytes().map_err(Error::Network)?;
+
+    serde_json::from_slice(&bytes).map_err(Error::Deserialization)
+}
+
+/// Deserializes a response body as it is read, without buffering it.
+#[cfg(feature = "stream-parse")]
+fn parse_json(response: reqwest::blocking::Response) -> Result<ApiResponse, Error> {
+    serde_json::from_reader(std::io::BufReader::new(response)).map_err(crate::client::read_error)
+}
+
+/// The blocking counterpart of the async client's status check.
+fn check_status(
+    response: reqwest::blocking::Response,
+) -> Result<reqwest::blocking::Response, Error> {
+    let status = response.status();
+
+    if let Some(error) = rate_limited(status, response.headers()) {
+        return Err(error);
+    }
+
+    if let Some(error) = non_json_error(status, response.headers()) {
+        return Err(error);
+    }
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().map_err(Error::Network)?;
+
+    Err(error_from_body(status, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{card::CardId, testing::mock_api};
+
+    #[tokio::test]
+    async fn get_by_name() {
+        let server = mock_api().await;
+        let base = server.uri();
+
+        // the blocking client can't run on the async test's thread
+        let (trent, missing) = std::thread::spawn(move || {
+            let client = Client::with_base_url(base);
+            (client.get_by_name("Trent"), client.get_by_name("Trnet"))
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(trent.unwrap().id(), CardId(78780140));
+        assert!(matches!(missing, Err(Error::NotFound)));
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::VecDeque;
+
+use crate::card::Card;
+
+/// A least-recently-used cache of query results, keyed by request URL.
+///
+/// Entries are kept in a list ordered from least to most recently used, so
+/// lookups are linear in the capacity; it is meant for the small capacities
+/// that deduplicate lookups within a deck import.
+#[derive(Debug)]
+pub(crate) struct LruCache {
+    capacity: usize,
+    entries: VecDeque<(String, Vec<Card>)>,
+}
+
+impl LruCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns a copy of the cards cached for `url`, marking them as the
+    /// most recently used.
+    pub(crate) fn get(&mut self, url: &str) -> Option<Vec<Card>> {
+        let index = self.entries.iter().position(|(key, _)| key == url)?;
+        let entry = self.entries.remove(index)?;
+        let cards = entry.1.clone();
+        self.entries.push_back(entry);
+
+        Some(cards)
+    }
+
+    /// Caches `cards` for `url`, evicting the least recently used entry if
+    /// the cache is full.
+    pub(crate) fn insert(&mut self, url: String, cards: Vec<Card>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some(index) = self.entries.iter().position(|(key, _)| *key == url) {
+            self.entries.remove(index);
+        } else if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back((url, cards));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.insert("a".to_string(), Vec::new());
+        cache.insert("b".to_string(), Vec::new());
+        assert!(cache.get("a").is_some());
+
+        cache.insert("c".to_string(), Vec::new());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+
+        let mut disabled = LruCache::new(0);
+        disabled.insert("a".to_string(), Vec::new());
+        assert!(disabled.get("a").is_none());
+    }
+}
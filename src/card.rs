@@ -1,15 +1,25 @@
-use std::fmt::{self, Display};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    num::ParseIntError,
+    str::FromStr,
+};
 
 use serde::{Deserialize, Serialize, de::IntoDeserializer};
 
+use crate::client::{Client, Error};
+
 /// Represents any Yu-Gi-Oh! card.
 ///
 /// This is an enum tagged by the card’s `frameType` field, which indicates
 /// the card’s overall category (e.g. `"effect"`, `"spell"`, `"trap"`).
 ///
 /// Each variant wraps a specific struct with fields that match the
-/// YGOProDeck API response for that card type.
-#[derive(Debug, Serialize, Deserialize)]
+/// YGOProDeck API response for that card type. Cards with a frame type this
+/// crate doesn't know deserialize to [`Card::Unknown`] instead of failing.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(tag = "frameType")]
 pub enum Card {
     /// A standard non-effect monster.
@@ -38,10 +48,12 @@ pub enum Card {
     /// A trap card.
     #[serde(rename = "trap")]
     Trap(TrapCard),
+    /// A Speed Duel skill card.
     #[serde(rename = "skill")]
-    Skill,
+    Skill(SkillCard),
+    /// A token, as summoned by other cards' effects.
     #[serde(rename = "token")]
-    Token,
+    Token(TokenCard),
     /// A Pendulum Monster
     ///
     /// Covers all pendulum-based frame types from the API:
@@ -51,22 +63,101 @@ pub enum Card {
     /// - `fusion_pendulum`
     /// - `synchro_pendulum`
     /// - `xyz_pendulum`
-    #[serde(
-        rename = "normal_pendulum",
-        alias = "effect_pendulum",
-        alias = "ritual_pendulum",
-        alias = "fusion_pendulum",
-        alias = "synchro_pendulum",
-        alias = "xyz_pendulum"
-    )]
+    #[serde(rename = "normal_pendulum")]
     Pendulum(PendulumMonster),
+    /// A card with a frame type this crate doesn't know yet, kept as the API
+    /// sent it.
+    #[serde(untagged)]
+    Unknown(UnknownCard),
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "frameType")]
+        enum Tagged {
+            #[serde(rename = "normal")]
+            Normal(NormalMonster),
+            #[serde(rename = "effect")]
+            Effect(EffectMonster),
+            #[serde(rename = "ritual")]
+            Ritual(RitualMonster),
+            #[serde(rename = "fusion")]
+            Fusion(FusionMonster),
+            #[serde(rename = "synchro")]
+            Synchro(SynchroMonster),
+            #[serde(rename = "xyz")]
+            Xyz(XyzMonster),
+            #[serde(rename = "link")]
+            Link(LinkMonster),
+            #[serde(rename = "spell")]
+            Spell(SpellCard),
+            #[serde(rename = "trap")]
+            Trap(TrapCard),
+            #[serde(rename = "skill")]
+            Skill(SkillCard),
+            #[serde(rename = "token")]
+            Token(TokenCard),
+            #[serde(
+                rename = "normal_pendulum",
+                alias = "effect_pendulum",
+                alias = "ritual_pendulum",
+                alias = "fusion_pendulum",
+                alias = "synchro_pendulum",
+                alias = "xyz_pendulum"
+            )]
+            Pendulum(PendulumMonster),
+            #[serde(other)]
+            Unknown,
+        }
+
+        // buffered so that an unknown card can keep its raw fields, while a
+        // known but malformed one still reports its own error
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        let card = match Tagged::deserialize(&raw).map_err(serde::de::Error::custom)? {
+            Tagged::Normal(m) => Card::Normal(m),
+            Tagged::Effect(m) => Card::Effect(m),
+            Tagged::Ritual(m) => Card::Ritual(m),
+            Tagged::Fusion(m) => Card::Fusion(m),
+            Tagged::Synchro(m) => Card::Synchro(m),
+            Tagged::Xyz(m) => Card::Xyz(m),
+            Tagged::Link(m) => Card::Link(m),
+            Tagged::Spell(s) => Card::Spell(s),
+            Tagged::Trap(t) => Card::Trap(t),
+            Tagged::Skill(s) => Card::Skill(s),
+            Tagged::Token(t) => Card::Token(t),
+            Tagged::Pendulum(m) => Card::Pendulum(m),
+            Tagged::Unknown => {
+                // `frameType` is kept in `frame_type`, so it must not also
+                // land in `info.extra`, which holds unrecognised fields only
+                let mut fields = raw.clone();
+                let frame_type = fields
+                    .as_object_mut()
+                    .and_then(|fields| fields.remove("frameType"));
+                Card::Unknown(UnknownCard {
+                    frame_type: frame_type
+                        .as_ref()
+                        .and_then(serde_json::Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                    info: CardInfo::deserialize(&fields).map_err(serde::de::Error::custom)?,
+                    raw,
+                })
+            }
+        };
+
+        Ok(card)
+    }
 }
 
 /// Shared metadata for all Yu-Gi-Oh! cards.
 ///
 /// This struct is flattened into the other card structs so their
 /// base information (name, description, ID, etc.) is directly accessible.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CardInfo {
     /// The unique ID of the card.
     pub id: CardId,
@@ -74,9 +165,17 @@ pub struct CardInfo {
     pub name: String,
     /// The description or effect text of the card.
     pub desc: String,
+    /// The archetype the card belongs to, if any (e.g. `"Blue-Eyes"`).
+    #[serde(default)]
+    pub archetype: Option<String>,
     /// A human-readable version of the card’s type (e.g. `"Effect Monster"`).
     #[serde(rename = "humanReadableCardType")]
     pub human_readable_card_type: String,
+    /// The monster's type line, split into its parts (e.g.
+    /// `["Spellcaster", "Tuner", "Effect"]`). Empty for Spells and Traps, and
+    /// in older responses.
+    #[serde(default)]
+    pub typeline: Vec<String>,
     /// The official YGOProDeck card page URL.
     pub ygoprodeck_url: String,
     /// Card set data, if available.
@@ -88,10 +187,106 @@ pub struct CardInfo {
     /// Market price data from multiple vendors.
     #[serde(rename = "card_prices", default)]
     pub prices: Vec<CardPrices>,
+    /// Extra metadata, only sent when the request asked for it with
+    /// `with_misc(true)`.
+    #[serde(
+        rename = "misc_info",
+        default,
+        deserialize_with = "first_misc_info",
+        serialize_with = "misc_info_array",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub misc: Option<MiscInfo>,
+    /// The card's status on the banlists, only sent for cards that are
+    /// restricted on at least one of them.
+    #[serde(rename = "banlist_info", default)]
+    pub banlist: Option<BanlistInfo>,
+    /// The fields the API sent that no other field of the card captures,
+    /// such as fields added to the API after this version of the crate.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CardInfo {
+    /// Whether the card has been printed in at least one set.
+    ///
+    /// Promotional and not yet released cards come without printings.
+    pub fn is_released(&self) -> bool {
+        !self.sets.is_empty()
+    }
+
+    /// Returns the artwork whose image ID is `id`, e.g. the passcode of one
+    /// of the card's alternate arts.
+    pub fn image_for_id(&self, id: u64) -> Option<&CardImage> {
+        self.images.iter().find(|image| image.id == id)
+    }
+
+    /// Returns the card's original artwork, the one sharing the card's ID,
+    /// or its first image if none does.
+    pub fn primary_image(&self) -> Option<&CardImage> {
+        self.image_for_id(self.id.0).or_else(|| self.images.first())
+    }
+}
+
+/// Extra card metadata returned when `misc=yes` is requested.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MiscInfo {
+    /// Total views of the card's page on YGOProDeck.
+    #[serde(default)]
+    pub views: u64,
+    /// Views of the card's page during the last week.
+    #[serde(rename = "viewsweek", default)]
+    pub views_week: u64,
+    #[serde(default)]
+    pub upvotes: u64,
+    #[serde(default)]
+    pub downvotes: u64,
+    /// The formats the card can be played in (e.g. `"TCG"`, `"GOAT"`).
+    #[serde(default)]
+    pub formats: Vec<String>,
+    /// TCG release date, as `YYYY-MM-DD`.
+    pub tcg_date: Option<String>,
+    /// OCG release date, as `YYYY-MM-DD`.
+    pub ocg_date: Option<String>,
+    /// The card's ID in Konami's official database.
+    pub konami_id: Option<u64>,
+    #[serde(default, deserialize_with = "bool_from_int")]
+    pub has_effect: bool,
+    /// The card's rarity in Master Duel, `None` for cards not in the game.
+    #[serde(default)]
+    pub md_rarity: Option<MasterDuelRarity>,
+}
+
+/// The rarities of Master Duel, which set a card's crafting cost.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MasterDuelRarity {
+    /// N, shown by the API as `"Common"`.
+    #[serde(rename = "Common", alias = "Normal", alias = "N")]
+    Normal,
+    #[serde(alias = "R")]
+    Rare,
+    #[serde(rename = "Super Rare", alias = "SR")]
+    SuperRare,
+    #[serde(rename = "Ultra Rare", alias = "UR")]
+    UltraRare,
+    /// A rarity not listed above, as the API sent it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A card's status on each banlist, `None` where it is unrestricted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BanlistInfo {
+    #[serde(default)]
+    pub ban_tcg: Option<BanStatus>,
+    #[serde(default)]
+    pub ban_ocg: Option<BanStatus>,
+    #[serde(default)]
+    pub ban_goat: Option<BanStatus>,
 }
 
 /// Represents a Normal Monster card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct NormalMonster {
     /// Common card metadata.
     #[serde(flatten)]
@@ -100,21 +295,21 @@ pub struct NormalMonster {
     pub attribute: Attribute,
     #[serde(deserialize_with = "zero_if_null")]
     pub level: u8,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     #[serde(rename = "type")]
     pub card_type: MonsterType,
 }
 
 /// Represents an Effect Monster card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct EffectMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     #[serde(deserialize_with = "zero_if_null")]
     pub level: u8,
     #[serde(rename = "type")]
@@ -122,14 +317,14 @@ pub struct EffectMonster {
 }
 
 /// Represents a Ritual Monster card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RitualMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     #[serde(deserialize_with = "zero_if_null")]
     pub level: u8,
     #[serde(rename = "type")]
@@ -137,14 +332,14 @@ pub struct RitualMonster {
 }
 
 /// Represents a Fusion Monster card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FusionMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     // this is needed because, for some reason, `Dracotail Shaurus`
     // returns null for its level, despite being a level 6
     #[serde(deserialize_with = "zero_if_null")]
@@ -154,14 +349,14 @@ pub struct FusionMonster {
 }
 
 /// Represents a Synchro Monster card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SynchroMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     #[serde(deserialize_with = "zero_if_null")]
     pub level: u8,
     #[serde(rename = "type")]
@@ -171,14 +366,14 @@ pub struct SynchroMonster {
 /// Represents an XYZ Monster card.
 ///
 /// The `rank` field corresponds to the “level” key in the API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct XyzMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     #[serde(rename = "level")]
     #[serde(deserialize_with = "zero_if_null")]
     pub rank: u8,
@@ -186,29 +381,35 @@ pub struct XyzMonster {
     pub card_type: MonsterType,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PendulumMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
-    pub def: i32,
+    pub atk: StatValue,
+    pub def: StatValue,
     #[serde(deserialize_with = "zero_if_null")]
     pub level: u8,
     #[serde(rename = "type")]
     pub card_type: MonsterType,
     pub scale: u8,
+    /// The text of the Pendulum Effect box, when the API sends it apart.
+    #[serde(default)]
+    pub pend_desc: Option<String>,
+    /// The text of the monster box, when the API sends it apart.
+    #[serde(default)]
+    pub monster_desc: Option<String>,
 }
 
 /// Represents a Link Monster card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct LinkMonster {
     #[serde(flatten)]
     pub info: CardInfo,
     pub race: MonsterRace,
     pub attribute: Attribute,
-    pub atk: i32,
+    pub atk: StatValue,
     pub linkval: u8,
     #[serde(rename = "type")]
     pub card_type: MonsterType,
@@ -217,7 +418,7 @@ pub struct LinkMonster {
 }
 
 /// Represents a Spell Card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SpellCard {
     #[serde(flatten)]
     pub info: CardInfo,
@@ -225,7 +426,7 @@ pub struct SpellCard {
 }
 
 /// Represents a Trap Card.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TrapCard {
     #[serde(flatten)]
     pub info: CardInfo,
@@ -235,8 +436,128 @@ pub struct TrapCard {
     pub race: TrapRace,
 }
 
+/// Represents a Speed Duel Skill Card.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkillCard {
+    #[serde(flatten)]
+    pub info: CardInfo,
+}
+
+/// Represents a Token.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TokenCard {
+    #[serde(flatten)]
+    pub info: CardInfo,
+    pub race: MonsterRace,
+    pub attribute: Attribute,
+    pub atk: StatValue,
+    pub def: StatValue,
+}
+
+/// A card whose `frameType` this crate doesn't know, see [`Card::Unknown`].
+///
+/// Only the fields shared by every card are parsed; the rest can be read
+/// from `raw`, which is also what the card serializes back to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCard {
+    /// The card's `frameType`, e.g. `"newthing"`.
+    pub frame_type: String,
+    pub info: CardInfo,
+    /// The card as the API sent it.
+    pub raw: serde_json::Value,
+}
+
+impl Serialize for UnknownCard {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.raw.serialize(serializer)
+    }
+}
+
+/// A monster's ATK or DEF, which some cards print as `?`.
+///
+/// The API sends `?` as a negative number (`-1`), and some sources as the
+/// string `"?"`; both deserialize to [`StatValue::Unknown`]. It serializes
+/// back to the API's `-1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatValue {
+    Known(i32),
+    /// A `?` stat, usually computed by the card's effect.
+    Unknown,
+}
+
+impl StatValue {
+    /// Returns the stat as a number, or `None` for a `?` stat.
+    pub fn value(self) -> Option<i32> {
+        match self {
+            StatValue::Known(value) => Some(value),
+            StatValue::Unknown => None,
+        }
+    }
+
+    pub fn is_unknown(self) -> bool {
+        self == StatValue::Unknown
+    }
+}
+
+impl From<i32> for StatValue {
+    fn from(value: i32) -> Self {
+        if value < 0 {
+            StatValue::Unknown
+        } else {
+            StatValue::Known(value)
+        }
+    }
+}
+
+impl Serialize for StatValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(self.value().unwrap_or(-1))
+    }
+}
+
+impl<'de> Deserialize<'de> for StatValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(i64),
+            Text(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(value) => {
+                Ok(i32::try_from(value).map_or(StatValue::Unknown, StatValue::from))
+            }
+            Raw::Text(text) if text.trim() == "?" => Ok(StatValue::Unknown),
+            Raw::Text(text) => text
+                .trim()
+                .parse::<i32>()
+                .map(StatValue::from)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+impl Display for StatValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatValue::Known(value) => write!(f, "{value}"),
+            StatValue::Unknown => write!(f, "?"),
+        }
+    }
+}
+
 /// Enum describing all possible frame types returned by the API.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum FrameType {
     Normal,
@@ -257,8 +578,103 @@ pub enum FrameType {
     Skill,
 }
 
+/// The mechanic used to summon a card, derived from its frame type.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum SummonMechanic {
+    /// Normal or Tribute Summoned from the Main Deck.
+    Normal,
+    /// Fusion Summoned from the Extra Deck using Fusion Materials.
+    Fusion,
+    /// Synchro Summoned from the Extra Deck using a Tuner + non-Tuners.
+    Synchro,
+    /// Xyz Summoned from the Extra Deck by overlaying monsters of the same Level.
+    Xyz,
+    /// Link Summoned from the Extra Deck using Link Materials.
+    Link,
+    /// Ritual Summoned with a Ritual Spell.
+    Ritual,
+    /// Pendulum Summoned using the Pendulum Scales.
+    Pendulum,
+    /// The card is not summoned (spells, traps, skills, tokens and unknown
+    /// cards).
+    None,
+}
+
+/// The broad kind of a card, see [`Card::category`].
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CardCategory {
+    /// Any monster, from the Main or the Extra Deck.
+    Monster,
+    Spell,
+    Trap,
+    /// A Speed Duel Skill Card.
+    Skill,
+    Token,
+    /// A card with a frame type this crate doesn't know.
+    Unknown,
+}
+
+/// Whether a number next to a monster's name is its Level or, for Xyz
+/// Monsters, its Rank, see [`Card::level_or_rank`].
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LevelKind {
+    Level,
+    Rank,
+}
+
+/// A restriction placed on a card by a banlist.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BanStatus {
+    Banned,
+    Limited,
+    #[serde(rename = "Semi-Limited")]
+    SemiLimited,
+}
+
+impl BanStatus {
+    /// The number of copies of the card allowed in a deck.
+    pub fn copies(&self) -> u8 {
+        match self {
+            BanStatus::Banned => 0,
+            BanStatus::Limited => 1,
+            BanStatus::SemiLimited => 2,
+        }
+    }
+}
+
+/// The banlists reported in [`BanlistInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Banlist {
+    Tcg,
+    Ocg,
+    Goat,
+}
+
+/// The formats the API lists in [`MiscInfo::formats`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Format {
+    #[serde(rename = "TCG")]
+    Tcg,
+    #[serde(rename = "OCG")]
+    Ocg,
+    #[serde(rename = "GOAT")]
+    Goat,
+    #[serde(rename = "OCG GOAT")]
+    OcgGoat,
+    #[serde(rename = "Edison")]
+    Edison,
+    #[serde(rename = "Duel Links")]
+    DuelLinks,
+    #[serde(rename = "Speed Duel")]
+    SpeedDuel,
+    #[serde(rename = "Master Duel")]
+    MasterDuel,
+    #[serde(rename = "Rush Duel")]
+    RushDuel,
+}
+
 /// All supported monster races (e.g., Dragon, Warrior, etc.).
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MonsterRace {
     Aqua,
     Beast,
@@ -294,7 +710,7 @@ pub enum MonsterRace {
 }
 
 /// Spell card subtypes (e.g., Equip, Field, Ritual).
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SpellRace {
     Normal,
     Field,
@@ -306,7 +722,7 @@ pub enum SpellRace {
 }
 
 /// Trap card subtypes (e.g., Continuous, Counter).
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TrapRace {
     Normal,
     Continuous,
@@ -314,7 +730,7 @@ pub enum TrapRace {
 }
 
 /// All monster type variants, such as “Fusion Monster” or “Effect Monster”.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MonsterType {
     #[serde(rename = "Effect Monster")]
     EffectMonster,
@@ -370,8 +786,92 @@ pub enum MonsterType {
     Token,
 }
 
+impl MonsterType {
+    /// Whether the monster is a Tuner, including Pendulum, Flip and Synchro
+    /// Tuners.
+    pub fn is_tuner(&self) -> bool {
+        matches!(
+            self,
+            MonsterType::FlipTunerEffectMonster
+                | MonsterType::NormalTunerMonster
+                | MonsterType::PendulumTunerEffectMonster
+                | MonsterType::TunerMonster
+                | MonsterType::SynchroTunerMonster
+        )
+    }
+
+    /// Whether the monster is a Pendulum Monster, whatever its other types.
+    pub fn is_pendulum(&self) -> bool {
+        matches!(
+            self,
+            MonsterType::PendulumEffectMonster
+                | MonsterType::PendulumEffectRitualMonster
+                | MonsterType::PendulumFlipEffectMonster
+                | MonsterType::PendulumNormalMonster
+                | MonsterType::PendulumTunerEffectMonster
+                | MonsterType::PendulumEffectFusionMonster
+                | MonsterType::SynchroPendulumEffectMonster
+                | MonsterType::XYZPendulumEffectMonster
+        )
+    }
+
+    /// Whether the monster is an Effect Monster.
+    ///
+    /// Gemini, Spirit, Toon, Union and effect Tuner monsters count as Effect
+    /// Monsters. The API doesn't tell Fusion, Synchro, Xyz and Link Monsters
+    /// with an effect from those without, so they return `false` unless
+    /// their type names an effect.
+    pub fn is_effect(&self) -> bool {
+        matches!(
+            self,
+            MonsterType::EffectMonster
+                | MonsterType::FlipEffectMonster
+                | MonsterType::FlipTunerEffectMonster
+                | MonsterType::GeminiMonster
+                | MonsterType::PendulumEffectMonster
+                | MonsterType::PendulumEffectRitualMonster
+                | MonsterType::PendulumFlipEffectMonster
+                | MonsterType::PendulumTunerEffectMonster
+                | MonsterType::RitualEffectMonster
+                | MonsterType::SpiritMonster
+                | MonsterType::ToonMonster
+                | MonsterType::TunerMonster
+                | MonsterType::UnionEffectMonster
+                | MonsterType::PendulumEffectFusionMonster
+                | MonsterType::SynchroPendulumEffectMonster
+                | MonsterType::XYZPendulumEffectMonster
+        )
+    }
+
+    /// Whether the monster has a Flip effect.
+    pub fn is_flip(&self) -> bool {
+        matches!(
+            self,
+            MonsterType::FlipEffectMonster
+                | MonsterType::FlipTunerEffectMonster
+                | MonsterType::PendulumFlipEffectMonster
+        )
+    }
+
+    pub fn is_gemini(&self) -> bool {
+        *self == MonsterType::GeminiMonster
+    }
+
+    pub fn is_spirit(&self) -> bool {
+        *self == MonsterType::SpiritMonster
+    }
+
+    pub fn is_toon(&self) -> bool {
+        *self == MonsterType::ToonMonster
+    }
+
+    pub fn is_union(&self) -> bool {
+        *self == MonsterType::UnionEffectMonster
+    }
+}
+
 /// Card attributes (LIGHT, DARK, FIRE, etc.).
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Attribute {
     Light,
@@ -384,7 +884,7 @@ pub enum Attribute {
 }
 
 /// Indicates the direction of a Link Monster’s markers.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum LinkMarker {
     Top,
     #[serde(rename = "Top-Left")]
@@ -401,26 +901,110 @@ pub enum LinkMarker {
 }
 
 /// Represents a set (printing) the card belongs to.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CardSet {
     #[serde(rename = "set_name")]
     pub name: String,
     #[serde(rename = "set_code")]
     pub code: String,
+    /// The full name of the rarity, e.g. `"Secret Rare"`.
     #[serde(rename = "set_rarity")]
     pub rarity: String,
     #[serde(rename = "set_rarity_code")]
-    pub rarity_code: String,
+    pub rarity_code: Rarity,
     #[serde(rename = "set_price")]
     pub price: String,
+    /// The printing's TCGPlayer product page, only sent for requests made
+    /// with `with_tcgplayer_data(true)`.
+    #[serde(rename = "set_url", default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The printing's edition (e.g. `"1st Edition"`), only sent with the
+    /// TCGPlayer data.
+    #[serde(
+        rename = "set_edition",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub edition: Option<String>,
+}
+
+impl CardSet {
+    /// Returns the printing's price in US dollars, or `None` if the API sent
+    /// something that isn't a number.
+    pub fn price_value(&self) -> Option<f64> {
+        self.price.parse().ok()
+    }
+
+    /// Returns the TCGPlayer product ID, read from [`CardSet::url`].
+    pub fn tcgplayer_product_id(&self) -> Option<u64> {
+        let url = self.url.as_deref()?;
+        let (_, rest) = url.split_once("/product/")?;
+
+        rest.split(['/', '?']).next()?.parse().ok()
+    }
+}
+
+/// The rarity of a printing, parsed from its code (e.g. `"(ScR)"`).
+///
+/// Codes this enum doesn't know, including the empty code of some
+/// printings, are kept as [`Rarity::Other`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Rarity {
+    #[serde(rename = "(C)")]
+    Common,
+    #[serde(rename = "(R)")]
+    Rare,
+    #[serde(rename = "(SR)")]
+    SuperRare,
+    #[serde(rename = "(UR)")]
+    UltraRare,
+    #[serde(rename = "(UtR)")]
+    UltimateRare,
+    #[serde(rename = "(ScR)")]
+    SecretRare,
+    #[serde(rename = "(PScR)")]
+    PrismaticSecretRare,
+    #[serde(rename = "(PlScR)")]
+    PlatinumSecretRare,
+    #[serde(rename = "(QCScR)")]
+    QuarterCenturySecretRare,
+    #[serde(rename = "(GR)")]
+    GhostRare,
+    #[serde(rename = "(StR)")]
+    StarlightRare,
+    #[serde(rename = "(CR)")]
+    CollectorsRare,
+    #[serde(rename = "(GUR)")]
+    GoldRare,
+    #[serde(rename = "(GScR)")]
+    GoldSecretRare,
+    #[serde(rename = "(PG)")]
+    PremiumGoldRare,
+    #[serde(rename = "(PlR)")]
+    PlatinumRare,
+    #[serde(rename = "(SP)")]
+    ShortPrint,
+    #[serde(rename = "(SSP)")]
+    SuperShortPrint,
+    #[serde(rename = "(SFR)")]
+    StarfoilRare,
+    #[serde(rename = "(MSR)")]
+    MosaicRare,
+    #[serde(rename = "(SHR)")]
+    ShatterfoilRare,
+    #[serde(rename = "(UPR)")]
+    UltraParallelRare,
+    /// A rarity code not listed above, as the API sent it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Unique identifier for a card.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CardId(pub u64);
 
 /// Image URLs for a card in various resolutions.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CardImage {
     pub id: u64,
     #[serde(rename = "image_url")]
@@ -432,7 +1016,7 @@ pub struct CardImage {
 }
 
 /// Market price information for a card across multiple vendors.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CardPrices {
     #[serde(rename = "cardmarket_price")]
     pub cardmarket: String,
@@ -446,87 +1030,1992 @@ pub struct CardPrices {
     pub coolstuffinc: String,
 }
 
-impl Display for MonsterRace {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let text = match self {
-            MonsterRace::Aqua => "Aqua",
-            MonsterRace::Beast => "Beast",
-            MonsterRace::BeastWarrior => "Beast-Warrior",
-            MonsterRace::CreatorGod => "Creator-God",
-            MonsterRace::Cyberse => "Cyberse",
-            MonsterRace::Dinosaur => "Dinosaur",
-            MonsterRace::DivineBeast => "Divine-Beast",
-            MonsterRace::Dragon => "Dragon",
-            MonsterRace::Fairy => "Fairy",
-            MonsterRace::Fiend => "Fiend",
-            MonsterRace::Fish => "Fish",
-            MonsterRace::Illusion => "Illusion",
-            MonsterRace::Insect => "Insect",
-            MonsterRace::Machine => "Machine",
-            MonsterRace::Plant => "Plant",
-            MonsterRace::Psychic => "Psychic",
-            MonsterRace::Pyro => "Pyro",
-            MonsterRace::Reptile => "Reptile",
-            MonsterRace::Rock => "Rock",
-            MonsterRace::SeaSerpent => "Sea Serpent",
-            MonsterRace::Spellcaster => "Spellcaster",
-            MonsterRace::Thunder => "Thunder",
-            MonsterRace::Warrior => "Warrior",
-            MonsterRace::WingedBeast => "Winged Beast",
-            MonsterRace::Wyrm => "Wyrm",
-            MonsterRace::Zombie => "Zombie",
+impl CardPrices {
+    /// Returns the price at `vendor`, or `None` if the API sent something
+    /// that isn't a number.
+    pub fn price(&self, vendor: Vendor) -> Option<f64> {
+        let price = match vendor {
+            Vendor::Cardmarket => &self.cardmarket,
+            Vendor::TcgPlayer => &self.tcgplayer,
+            Vendor::Ebay => &self.ebay,
+            Vendor::Amazon => &self.amazon,
+            Vendor::CoolStuffInc => &self.coolstuffinc,
         };
-        write!(f, "{}", text)
+
+        price.parse().ok()
     }
 }
 
-impl Display for Attribute {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let text = match self {
-            Attribute::Light => "LIGHT",
-            Attribute::Dark => "DARK",
-            Attribute::Water => "WATER",
-            Attribute::Fire => "FIRE",
-            Attribute::Earth => "EARTH",
-            Attribute::Wind => "WIND",
-            Attribute::Divine => "DIVINE",
-        };
-        write!(f, "{}", text)
+/// A vendor whose prices are listed in [`CardPrices`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Vendor {
+    /// Cardmarket, whose prices are in euros.
+    Cardmarket,
+    TcgPlayer,
+    Ebay,
+    Amazon,
+    CoolStuffInc,
+}
+
+/// The stats shared by every monster, whatever its card frame.
+///
+/// See [`Card::as_monster`] to get one from a [`Card`].
+pub trait Monster {
+    fn atk(&self) -> StatValue;
+
+    /// The DEF, or `None` for Link Monsters, which have none.
+    fn def(&self) -> Option<StatValue>;
+
+    /// The Level, or `None` for monsters that have a Rank or Link Rating
+    /// instead, and for Tokens, whose Level the API doesn't send.
+    fn level(&self) -> Option<u8>;
+
+    /// The Rank of an Xyz Monster, Xyz Pendulum Monsters included.
+    fn rank(&self) -> Option<u8> {
+        None
+    }
+
+    /// The Link Rating of a Link Monster.
+    fn link_rating(&self) -> Option<u8> {
+        None
+    }
+
+    fn race(&self) -> &MonsterRace;
+
+    fn attribute(&self) -> &Attribute;
+
+    /// The monster's card type, or `None` for Tokens, which have none.
+    fn card_type(&self) -> Option<&MonsterType>;
+
+    /// The Link Arrows of a Link Monster.
+    fn link_markers(&self) -> &[LinkMarker] {
+        &[]
+    }
+
+    /// The Pendulum Scale of a Pendulum Monster.
+    fn scale(&self) -> Option<u8> {
+        None
     }
 }
 
-impl Display for LinkMarker {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let text = match self {
-            LinkMarker::Top => "Top",
-            LinkMarker::TopLeft => "Top-Left",
-            LinkMarker::TopRight => "Top-Right",
-            LinkMarker::Left => "Left",
-            LinkMarker::Right => "Right",
-            LinkMarker::Bottom => "Bottom",
-            LinkMarker::BottomLeft => "Bottom-Left",
-            LinkMarker::BottomRight => "Bottom-Right",
-        };
-        write!(f, "{}", text)
+/// Implements [`Monster`] for the structs with a Level, an ATK and a DEF.
+macro_rules! impl_leveled_monster {
+    ($($monster:ty),+) => {
+        $(
+            impl Monster for $monster {
+                fn atk(&self) -> StatValue {
+                    self.atk
+                }
+
+                fn def(&self) -> Option<StatValue> {
+                    Some(self.def)
+                }
+
+                fn level(&self) -> Option<u8> {
+                    Some(self.level)
+                }
+
+                fn race(&self) -> &MonsterRace {
+                    &self.race
+                }
+
+                fn attribute(&self) -> &Attribute {
+                    &self.attribute
+                }
+
+                fn card_type(&self) -> Option<&MonsterType> {
+                    Some(&self.card_type)
+                }
+            }
+        )+
+    };
+}
+
+impl_leveled_monster!(
+    NormalMonster,
+    EffectMonster,
+    RitualMonster,
+    FusionMonster,
+    SynchroMonster
+);
+
+impl Monster for PendulumMonster {
+    fn atk(&self) -> StatValue {
+        self.atk
+    }
+
+    fn def(&self) -> Option<StatValue> {
+        Some(self.def)
+    }
+
+    fn level(&self) -> Option<u8> {
+        match self.card_type {
+            MonsterType::XYZPendulumEffectMonster => None,
+            _ => Some(self.level),
+        }
+    }
+
+    // the API sends an Xyz Pendulum's Rank in its `level` field
+    fn rank(&self) -> Option<u8> {
+        match self.card_type {
+            MonsterType::XYZPendulumEffectMonster => Some(self.level),
+            _ => None,
+        }
+    }
+
+    fn race(&self) -> &MonsterRace {
+        &self.race
+    }
+
+    fn attribute(&self) -> &Attribute {
+        &self.attribute
+    }
+
+    fn card_type(&self) -> Option<&MonsterType> {
+        Some(&self.card_type)
+    }
+
+    fn scale(&self) -> Option<u8> {
+        Some(self.scale)
     }
 }
 
-fn zero_if_null<'de, D>(deserializer: D) -> Result<u8, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    Ok(Option::<u8>::deserialize(deserializer)?.unwrap_or(0))
+impl Monster for XyzMonster {
+    fn atk(&self) -> StatValue {
+        self.atk
+    }
+
+    fn def(&self) -> Option<StatValue> {
+        Some(self.def)
+    }
+
+    fn level(&self) -> Option<u8> {
+        None
+    }
+
+    fn rank(&self) -> Option<u8> {
+        Some(self.rank)
+    }
+
+    fn race(&self) -> &MonsterRace {
+        &self.race
+    }
+
+    fn attribute(&self) -> &Attribute {
+        &self.attribute
+    }
+
+    fn card_type(&self) -> Option<&MonsterType> {
+        Some(&self.card_type)
+    }
 }
 
-fn empty_to_normal_trap<'de, D>(deserializer: D) -> Result<TrapRace, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let s: String = String::deserialize(deserializer)?;
+impl Monster for LinkMonster {
+    fn atk(&self) -> StatValue {
+        self.atk
+    }
 
-    if s.trim().is_empty() {
-        Ok(TrapRace::Normal)
-    } else {
-        TrapRace::deserialize(s.into_deserializer())
+    fn def(&self) -> Option<StatValue> {
+        None
+    }
+
+    fn level(&self) -> Option<u8> {
+        None
+    }
+
+    fn link_rating(&self) -> Option<u8> {
+        Some(self.linkval)
+    }
+
+    fn race(&self) -> &MonsterRace {
+        &self.race
+    }
+
+    fn attribute(&self) -> &Attribute {
+        &self.attribute
+    }
+
+    fn card_type(&self) -> Option<&MonsterType> {
+        Some(&self.card_type)
+    }
+
+    fn link_markers(&self) -> &[LinkMarker] {
+        &self.link_markers
+    }
+}
+
+impl Monster for TokenCard {
+    fn atk(&self) -> StatValue {
+        self.atk
+    }
+
+    fn def(&self) -> Option<StatValue> {
+        Some(self.def)
+    }
+
+    fn level(&self) -> Option<u8> {
+        None
+    }
+
+    fn race(&self) -> &MonsterRace {
+        &self.race
+    }
+
+    fn attribute(&self) -> &Attribute {
+        &self.attribute
+    }
+
+    fn card_type(&self) -> Option<&MonsterType> {
+        None
+    }
+}
+
+impl Card {
+    /// Returns the metadata shared by every card.
+    pub fn info(&self) -> &CardInfo {
+        match self {
+            Card::Normal(m) => &m.info,
+            Card::Effect(m) => &m.info,
+            Card::Ritual(m) => &m.info,
+            Card::Fusion(m) => &m.info,
+            Card::Synchro(m) => &m.info,
+            Card::Xyz(m) => &m.info,
+            Card::Link(m) => &m.info,
+            Card::Spell(s) => &s.info,
+            Card::Trap(t) => &t.info,
+            Card::Skill(s) => &s.info,
+            Card::Token(t) => &t.info,
+            Card::Pendulum(m) => &m.info,
+            Card::Unknown(u) => &u.info,
+        }
+    }
+
+    /// Returns the card's name.
+    pub fn name(&self) -> &str {
+        &self.info().name
+    }
+
+    /// Returns the card's ID.
+    pub fn id(&self) -> CardId {
+        self.info().id
+    }
+
+    /// Returns the card as a [`Monster`], or `None` for Spells, Traps and
+    /// Skills. Tokens count as monsters.
+    pub fn as_monster(&self) -> Option<&dyn Monster> {
+        match self {
+            Card::Normal(m) => Some(m),
+            Card::Effect(m) => Some(m),
+            Card::Ritual(m) => Some(m),
+            Card::Fusion(m) => Some(m),
+            Card::Synchro(m) => Some(m),
+            Card::Xyz(m) => Some(m),
+            Card::Link(m) => Some(m),
+            Card::Pendulum(m) => Some(m),
+            Card::Token(t) => Some(t),
+            Card::Spell(_) | Card::Trap(_) | Card::Skill(_) | Card::Unknown(_) => None,
+        }
+    }
+
+    /// Returns a monster's Level, or its Rank for an Xyz or Xyz Pendulum
+    /// Monster, tagged with which of the two it is.
+    ///
+    /// Returns `None` for Link Monsters, which have neither, for Tokens,
+    /// whose Level the API doesn't send, and for every card that isn't a
+    /// monster.
+    pub fn level_or_rank(&self) -> Option<(LevelKind, u8)> {
+        let monster = self.as_monster()?;
+
+        match (monster.level(), monster.rank()) {
+            (Some(level), _) => Some((LevelKind::Level, level)),
+            (None, Some(rank)) => Some((LevelKind::Rank, rank)),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns whether the card is a monster, a Spell, a Trap, a Skill or a
+    /// Token, or [`CardCategory::Unknown`] for a [`Card::Unknown`].
+    pub fn category(&self) -> CardCategory {
+        match self {
+            Card::Normal(_)
+            | Card::Effect(_)
+            | Card::Ritual(_)
+            | Card::Fusion(_)
+            | Card::Synchro(_)
+            | Card::Xyz(_)
+            | Card::Link(_)
+            | Card::Pendulum(_) => CardCategory::Monster,
+            Card::Spell(_) => CardCategory::Spell,
+            Card::Trap(_) => CardCategory::Trap,
+            Card::Skill(_) => CardCategory::Skill,
+            Card::Token(_) => CardCategory::Token,
+            Card::Unknown(_) => CardCategory::Unknown,
+        }
+    }
+
+    /// Returns the card's `type`, as the API names it (e.g. `"Spell Card"`).
+    pub(crate) fn type_name(&self) -> String {
+        match self {
+            Card::Spell(_) => "Spell Card".to_string(),
+            Card::Trap(_) => "Trap Card".to_string(),
+            Card::Skill(_) => "Skill Card".to_string(),
+            Card::Token(_) => "Token".to_string(),
+            Card::Unknown(u) => u.raw["type"].as_str().unwrap_or_default().to_string(),
+            _ => self
+                .as_monster()
+                .and_then(Monster::card_type)
+                .map(MonsterType::to_string)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the mechanic used to summon this card.
+    ///
+    /// Pendulum monsters always return [`SummonMechanic::Pendulum`], even when
+    /// they are also Fusion, Synchro, Ritual or Xyz monsters.
+    pub fn summon_mechanic(&self) -> SummonMechanic {
+        match self {
+            Card::Normal(_) | Card::Effect(_) => SummonMechanic::Normal,
+            Card::Ritual(_) => SummonMechanic::Ritual,
+            Card::Fusion(_) => SummonMechanic::Fusion,
+            Card::Synchro(_) => SummonMechanic::Synchro,
+            Card::Xyz(_) => SummonMechanic::Xyz,
+            Card::Link(_) => SummonMechanic::Link,
+            Card::Pendulum(_) => SummonMechanic::Pendulum,
+            Card::Spell(_) | Card::Trap(_) | Card::Skill(_) | Card::Token(_) | Card::Unknown(_) => {
+                SummonMechanic::None
+            }
+        }
+    }
+
+    /// Returns the card's images with duplicated entries removed, keeping the
+    /// first occurrence of each image URL.
+    pub fn unique_images(&self) -> Vec<&CardImage> {
+        let info = self.info();
+        let mut images: Vec<&CardImage> = Vec::with_capacity(info.images.len());
+
+        for image in &info.images {
+            if !images.iter().any(|i| i.url == image.url) {
+                images.push(image);
+            }
+        }
+
+        images
+    }
+
+    /// Returns the card's page in Konami's official database, where its
+    /// rulings are published.
+    ///
+    /// The URL is built from the Konami ID, so this is `None` unless the card
+    /// was fetched with `with_misc(true)`.
+    pub fn konami_db_url(&self) -> Option<String> {
+        let konami_id = self.info().misc.as_ref()?.konami_id?;
+
+        Some(format!(
+            "https://www.db.yugioh-card.com/yugiohdb/card_search.action?ope=2&cid={konami_id}"
+        ))
+    }
+
+    /// Returns the card's ATK plus DEF, a rough measure of its raw power.
+    ///
+    /// Link Monsters have no DEF, so only their ATK counts, and a `?` value
+    /// counts as 0. Returns `None` for anything that isn't a monster or a
+    /// Token.
+    pub fn stat_total(&self) -> Option<u32> {
+        let monster = self.as_monster()?;
+        let stat = |value: StatValue| value.value().map_or(0, |v| u32::try_from(v).unwrap_or(0));
+
+        Some(stat(monster.atk()) + monster.def().map_or(0, stat))
+    }
+
+    /// Returns the key to sort cards by ATK with [`slice::sort_by_key`],
+    /// lowest first.
+    ///
+    /// A `?` ATK sorts below every number, along with the cards that have no
+    /// ATK at all.
+    pub fn by_atk(&self) -> Option<i32> {
+        self.as_monster()?.atk().value()
+    }
+
+    /// Returns the key to sort cards by DEF, lowest first, see
+    /// [`Card::by_atk`]. Link Monsters have no DEF.
+    pub fn by_def(&self) -> Option<i32> {
+        self.as_monster()?.def()?.value()
+    }
+
+    /// Returns the key to sort cards by Level, or Rank for Xyz Monsters,
+    /// lowest first. Cards with neither sort first.
+    pub fn by_level(&self) -> Option<u8> {
+        self.level_or_rank().map(|(_, level)| level)
+    }
+
+    /// Returns the key to sort cards by name, alphabetically and ignoring
+    /// case.
+    ///
+    /// The key is a new string, so prefer [`slice::sort_by_cached_key`].
+    pub fn by_name(&self) -> String {
+        self.name().to_lowercase()
+    }
+
+    /// Compares cards by ATK for [`slice::sort_by`], highest first, e.g.
+    /// `cards.sort_by(Card::by_atk_desc)`. `?` ATK sorts last.
+    pub fn by_atk_desc(a: &Card, b: &Card) -> Ordering {
+        b.by_atk().cmp(&a.by_atk())
+    }
+
+    /// Compares cards by DEF, highest first, see [`Card::by_atk_desc`].
+    pub fn by_def_desc(a: &Card, b: &Card) -> Ordering {
+        b.by_def().cmp(&a.by_def())
+    }
+
+    /// Compares cards by Level or Rank, highest first, see
+    /// [`Card::by_atk_desc`].
+    pub fn by_level_desc(a: &Card, b: &Card) -> Ordering {
+        b.by_level().cmp(&a.by_level())
+    }
+
+    /// Returns the Pendulum Effect and the monster text (effect or flavor
+    /// text) of a Pendulum Monster, as `(pendulum_effect, monster_effect)`,
+    /// or `None` for every other card.
+    ///
+    /// `pend_desc` and `monster_desc` are used when the API sends them,
+    /// otherwise the combined `desc` is split on its dashed separator and the
+    /// `[ Pendulum Effect ]`-style headers are dropped.
+    pub fn pendulum_parts(&self) -> Option<(String, String)> {
+        let Card::Pendulum(m) = self else {
+            return None;
+        };
+
+        if let (Some(pend), Some(monster)) = (&m.pend_desc, &m.monster_desc) {
+            return Some((pend.clone(), monster.clone()));
+        }
+
+        let strip_header = |part: &str| {
+            let part = part.trim();
+            let part = match part.strip_prefix('[') {
+                Some(rest) => rest.split_once(']').map_or(part, |(_, text)| text),
+                None => part,
+            };
+            part.trim().to_string()
+        };
+
+        match m.info.desc.split_once(PENDULUM_SEPARATOR) {
+            Some((pend, monster)) => Some((
+                strip_header(pend),
+                strip_header(monster.trim_start_matches('-')),
+            )),
+            None => Some((String::new(), strip_header(&m.info.desc))),
+        }
+    }
+
+    /// Returns how many copies of this card a deck may run in `format` under
+    /// `banlist`: 0 if the card isn't legal in the format at all, otherwise
+    /// the banlist's limit, 3 for unrestricted cards.
+    ///
+    /// The formats are only known when the card was fetched with
+    /// `with_misc(true)`; without them the card is assumed to be legal.
+    pub fn copies_allowed(&self, format: Format, banlist: Banlist) -> u8 {
+        let info = self.info();
+        let format = format.to_string();
+
+        if let Some(misc) = &info.misc
+            && !misc.formats.contains(&format)
+        {
+            return 0;
+        }
+
+        let status = info.banlist.as_ref().and_then(|b| match banlist {
+            Banlist::Tcg => b.ban_tcg,
+            Banlist::Ocg => b.ban_ocg,
+            Banlist::Goat => b.ban_goat,
+        });
+
+        status.map_or(3, |s| s.copies())
+    }
+
+    /// Returns how many copies of this card a player may control at once, as
+    /// stated by a "You can only control N" clause in its text.
+    ///
+    /// This is a heuristic parse of the effect text, not rules data: it reads
+    /// the first such clause, whether it is written with a digit or a word
+    /// ("one"), and returns `None` when there is none. It is unrelated to the
+    /// banlist, which limits the copies allowed in a deck.
+    pub fn max_control(&self) -> Option<u8> {
+        const CLAUSE: &str = "you can only control ";
+
+        let desc = self.info().desc.to_lowercase();
+        let start = desc.find(CLAUSE)? + CLAUSE.len();
+        let count = desc[start..].split(|c: char| !c.is_alphanumeric()).next()?;
+
+        match count {
+            "one" => Some(1),
+            "two" => Some(2),
+            "three" => Some(3),
+            n => n.parse().ok(),
+        }
+    }
+}
+
+impl CardImage {
+    /// Downloads the full-size artwork.
+    pub async fn download(&self, client: &Client) -> Result<Vec<u8>, Error> {
+        client.download(&self.url).await
+    }
+
+    /// Downloads the small artwork.
+    pub async fn download_small(&self, client: &Client) -> Result<Vec<u8>, Error> {
+        client.download(&self.url_small).await
+    }
+
+    /// Downloads the artwork cropped to the card's illustration.
+    pub async fn download_cropped(&self, client: &Client) -> Result<Vec<u8>, Error> {
+        client.download(&self.url_cropped).await
+    }
+}
+
+impl Display for MonsterRace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            MonsterRace::Aqua => "Aqua",
+            MonsterRace::Beast => "Beast",
+            MonsterRace::BeastWarrior => "Beast-Warrior",
+            MonsterRace::CreatorGod => "Creator-God",
+            MonsterRace::Cyberse => "Cyberse",
+            MonsterRace::Dinosaur => "Dinosaur",
+            MonsterRace::DivineBeast => "Divine-Beast",
+            MonsterRace::Dragon => "Dragon",
+            MonsterRace::Fairy => "Fairy",
+            MonsterRace::Fiend => "Fiend",
+            MonsterRace::Fish => "Fish",
+            MonsterRace::Illusion => "Illusion",
+            MonsterRace::Insect => "Insect",
+            MonsterRace::Machine => "Machine",
+            MonsterRace::Plant => "Plant",
+            MonsterRace::Psychic => "Psychic",
+            MonsterRace::Pyro => "Pyro",
+            MonsterRace::Reptile => "Reptile",
+            MonsterRace::Rock => "Rock",
+            MonsterRace::SeaSerpent => "Sea Serpent",
+            MonsterRace::Spellcaster => "Spellcaster",
+            MonsterRace::Thunder => "Thunder",
+            MonsterRace::Warrior => "Warrior",
+            MonsterRace::WingedBeast => "Winged Beast",
+            MonsterRace::Wyrm => "Wyrm",
+            MonsterRace::Zombie => "Zombie",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Display for SpellRace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            SpellRace::Normal => "Normal",
+            SpellRace::Field => "Field",
+            SpellRace::Equip => "Equip",
+            SpellRace::Continuous => "Continuous",
+            SpellRace::QuickPlay => "Quick-Play",
+            SpellRace::Ritual => "Ritual",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Display for TrapRace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            TrapRace::Normal => "Normal",
+            TrapRace::Continuous => "Continuous",
+            TrapRace::Counter => "Counter",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Display for MonsterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            MonsterType::EffectMonster => "Effect Monster",
+            MonsterType::FlipEffectMonster => "Flip Effect Monster",
+            MonsterType::FlipTunerEffectMonster => "Flip Tuner Effect Monster",
+            MonsterType::GeminiMonster => "Gemini Monster",
+            MonsterType::NormalMonster => "Normal Monster",
+            MonsterType::NormalTunerMonster => "Normal Tuner Monster",
+            MonsterType::PendulumEffectMonster => "Pendulum Effect Monster",
+            MonsterType::PendulumEffectRitualMonster => "Pendulum Effect Ritual Monster",
+            MonsterType::PendulumFlipEffectMonster => "Pendulum Flip Effect Monster",
+            MonsterType::PendulumNormalMonster => "Pendulum Normal Monster",
+            MonsterType::PendulumTunerEffectMonster => "Pendulum Tuner Effect Monster",
+            MonsterType::RitualEffectMonster => "Ritual Effect Monster",
+            MonsterType::RitualMonster => "Ritual Monster",
+            MonsterType::SpiritMonster => "Spirit Monster",
+            MonsterType::ToonMonster => "Toon Monster",
+            MonsterType::TunerMonster => "Tuner Monster",
+            MonsterType::UnionEffectMonster => "Union Effect Monster",
+            MonsterType::FusionMonster => "Fusion Monster",
+            MonsterType::LinkMonster => "Link Monster",
+            MonsterType::PendulumEffectFusionMonster => "Pendulum Effect Fusion Monster",
+            MonsterType::SynchroMonster => "Synchro Monster",
+            MonsterType::SynchroPendulumEffectMonster => "Synchro Pendulum Effect Monster",
+            MonsterType::SynchroTunerMonster => "Synchro Tuner Monster",
+            MonsterType::XYZMonster => "XYZ Monster",
+            MonsterType::XYZPendulumEffectMonster => "XYZ Pendulum Effect Monster",
+            MonsterType::Token => "Token",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Attribute::Light => "LIGHT",
+            Attribute::Dark => "DARK",
+            Attribute::Water => "WATER",
+            Attribute::Fire => "FIRE",
+            Attribute::Earth => "EARTH",
+            Attribute::Wind => "WIND",
+            Attribute::Divine => "DIVINE",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Display for LinkMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            LinkMarker::Top => "Top",
+            LinkMarker::TopLeft => "Top-Left",
+            LinkMarker::TopRight => "Top-Right",
+            LinkMarker::Left => "Left",
+            LinkMarker::Right => "Right",
+            LinkMarker::Bottom => "Bottom",
+            LinkMarker::BottomLeft => "Bottom-Left",
+            LinkMarker::BottomRight => "Bottom-Right",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Format::Tcg => "TCG",
+            Format::Ocg => "OCG",
+            Format::Goat => "GOAT",
+            Format::OcgGoat => "OCG GOAT",
+            Format::Edison => "Edison",
+            Format::DuelLinks => "Duel Links",
+            Format::SpeedDuel => "Speed Duel",
+            Format::MasterDuel => "Master Duel",
+            Format::RushDuel => "Rush Duel",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl FromStr for MonsterRace {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s {
+            "Aqua" => MonsterRace::Aqua,
+            "Beast" => MonsterRace::Beast,
+            "Beast-Warrior" => MonsterRace::BeastWarrior,
+            "Creator-God" => MonsterRace::CreatorGod,
+            "Cyberse" => MonsterRace::Cyberse,
+            "Dinosaur" => MonsterRace::Dinosaur,
+            "Divine-Beast" => MonsterRace::DivineBeast,
+            "Dragon" => MonsterRace::Dragon,
+            "Fairy" => MonsterRace::Fairy,
+            "Fiend" => MonsterRace::Fiend,
+            "Fish" => MonsterRace::Fish,
+            "Illusion" => MonsterRace::Illusion,
+            "Insect" => MonsterRace::Insect,
+            "Machine" => MonsterRace::Machine,
+            "Plant" => MonsterRace::Plant,
+            "Psychic" => MonsterRace::Psychic,
+            "Pyro" => MonsterRace::Pyro,
+            "Reptile" => MonsterRace::Reptile,
+            "Rock" => MonsterRace::Rock,
+            "Sea Serpent" => MonsterRace::SeaSerpent,
+            "Spellcaster" => MonsterRace::Spellcaster,
+            "Thunder" => MonsterRace::Thunder,
+            "Warrior" => MonsterRace::Warrior,
+            "Winged Beast" => MonsterRace::WingedBeast,
+            "Wyrm" => MonsterRace::Wyrm,
+            "Zombie" => MonsterRace::Zombie,
+            _ => return Err(ParseError::new("monster race", s)),
+        };
+        Ok(value)
+    }
+}
+
+impl TryFrom<&str> for MonsterRace {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for SpellRace {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s {
+            "Normal" => SpellRace::Normal,
+            "Field" => SpellRace::Field,
+            "Equip" => SpellRace::Equip,
+            "Continuous" => SpellRace::Continuous,
+            "Quick-Play" => SpellRace::QuickPlay,
+            "Ritual" => SpellRace::Ritual,
+            _ => return Err(ParseError::new("Spell race", s)),
+        };
+        Ok(value)
+    }
+}
+
+impl TryFrom<&str> for SpellRace {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for TrapRace {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s {
+            "Normal" => TrapRace::Normal,
+            "Continuous" => TrapRace::Continuous,
+            "Counter" => TrapRace::Counter,
+            _ => return Err(ParseError::new("Trap race", s)),
+        };
+        Ok(value)
+    }
+}
+
+impl TryFrom<&str> for TrapRace {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Attribute {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s {
+            "LIGHT" => Attribute::Light,
+            "DARK" => Attribute::Dark,
+            "WATER" => Attribute::Water,
+            "FIRE" => Attribute::Fire,
+            "EARTH" => Attribute::Earth,
+            "WIND" => Attribute::Wind,
+            "DIVINE" => Attribute::Divine,
+            _ => return Err(ParseError::new("attribute", s)),
+        };
+        Ok(value)
+    }
+}
+
+impl TryFrom<&str> for Attribute {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// The error returned when parsing a name that matches none of an enum's
+/// variants, e.g. an unknown attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: &'static str,
+    input: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: &'static str, input: &str) -> Self {
+        Self {
+            kind,
+            input: input.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} `{}`", self.kind, self.input)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Cards hash by their ID alone, which is consistent with their equality:
+/// equal cards always share an ID.
+impl Hash for Card {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+/// Prints a one-line summary of the card: its name and type, then for
+/// monsters their Attribute, Type, Level (`★`), Rank (`☆`) or Link Rating,
+/// and ATK/DEF, e.g. `Trent [Normal Monster] EARTH/Plant ★5 1500/1800`.
+impl Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = self.info();
+        let card_type = match info.human_readable_card_type.as_str() {
+            "" => self.type_name(),
+            human_readable => human_readable.to_string(),
+        };
+        write!(f, "{} [{card_type}]", info.name)?;
+
+        let Some(monster) = self.as_monster() else {
+            return Ok(());
+        };
+        write!(f, " {}/{}", monster.attribute(), monster.race())?;
+        if let Some(level) = monster.level() {
+            write!(f, " ★{level}")?;
+        } else if let Some(rank) = monster.rank() {
+            write!(f, " ☆{rank}")?;
+        } else if let Some(link) = monster.link_rating() {
+            write!(f, " LINK-{link}")?;
+        }
+        write!(f, " {}", monster.atk())?;
+        if let Some(def) = monster.def() {
+            write!(f, "/{def}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for CardId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for CardId {
+    type Err = ParseIntError;
+
+    /// Parses a card's passcode, e.g. `"89631139"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(CardId)
+    }
+}
+
+/// The dashed line between the two boxes of a Pendulum Monster's `desc`.
+const PENDULUM_SEPARATOR: &str = "----------------------------------------";
+
+/// Computes how the price of each printing changed between two snapshots of
+/// the same card, as `(set_code, new_price - old_price)` pairs.
+///
+/// Printings are matched by set code and rarity, and listed in the order of
+/// `new`. Printings missing from either snapshot, or whose price isn't a
+/// number, are skipped.
+pub fn price_deltas(old: &Card, new: &Card) -> Vec<(String, f64)> {
+    let (old, new) = (old.info(), new.info());
+
+    new.sets
+        .iter()
+        .filter_map(|set| {
+            let previous = old
+                .sets
+                .iter()
+                .find(|s| s.code == set.code && s.rarity_code == set.rarity_code)?;
+            let delta = set.price_value()? - previous.price_value()?;
+            Some((set.code.clone(), delta))
+        })
+        .collect()
+}
+
+/// Groups cards by their archetype, keeping the order of `cards` within each
+/// group. Cards outside any archetype are grouped under `None`.
+pub fn group_by_archetype(cards: Vec<Card>) -> HashMap<Option<String>, Vec<Card>> {
+    let mut groups: HashMap<Option<String>, Vec<Card>> = HashMap::new();
+
+    for card in cards {
+        let archetype = card.info().archetype.clone();
+        groups.entry(archetype).or_default().push(card);
+    }
+
+    groups
+}
+
+fn zero_if_null<'de, D>(deserializer: D) -> Result<u8, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<u8>::deserialize(deserializer)?.unwrap_or(0))
+}
+
+// the API sends `misc_info` as an array that always holds a single element
+fn first_misc_info<'de, D>(deserializer: D) -> Result<Option<MiscInfo>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<Vec<MiscInfo>>::deserialize(deserializer)?.and_then(|v| v.into_iter().next()))
+}
+
+fn misc_info_array<S>(misc: &Option<MiscInfo>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    misc.as_slice().serialize(serializer)
+}
+
+fn bool_from_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum IntOrBool {
+        Int(u8),
+        Bool(bool),
+    }
+
+    Ok(match IntOrBool::deserialize(deserializer)? {
+        IntOrBool::Int(i) => i != 0,
+        IntOrBool::Bool(b) => b,
+    })
+}
+
+fn empty_to_normal_trap<'de, D>(deserializer: D) -> Result<TrapRace, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: String = String::deserialize(deserializer)?;
+
+    if s.trim().is_empty() {
+        Ok(TrapRace::Normal)
+    } else {
+        TrapRace::deserialize(s.into_deserializer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{card, card_image_json};
+
+    #[test]
+    fn summon_mechanic() {
+        let synchro = card(
+            "synchro",
+            serde_json::json!({
+                "type": "Synchro Monster",
+                "race": "Dragon",
+                "attribute": "WIND",
+                "atk": 2500,
+                "def": 2000,
+                "level": 8
+            }),
+        );
+        assert_eq!(synchro.summon_mechanic(), SummonMechanic::Synchro);
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert_eq!(spell.summon_mechanic(), SummonMechanic::None);
+
+        let skill = card("skill", serde_json::json!({ "race": "Yami Yugi" }));
+        assert_eq!(skill.summon_mechanic(), SummonMechanic::None);
+    }
+
+    #[test]
+    fn misc_info() {
+        let spell = card(
+            "spell",
+            serde_json::json!({
+                "race": "Normal",
+                "misc_info": [{
+                    "views": 1000,
+                    "viewsweek": 10,
+                    "upvotes": 5,
+                    "downvotes": 1,
+                    "formats": ["TCG", "OCG", "GOAT"],
+                    "tcg_date": "2002-03-08",
+                    "ocg_date": "1999-05-27",
+                    "konami_id": 4844,
+                    "has_effect": 1
+                }]
+            }),
+        );
+        let misc = spell.info().misc.as_ref().unwrap();
+        assert_eq!(misc.views_week, 10);
+        assert_eq!(misc.formats, vec!["TCG", "OCG", "GOAT"]);
+        assert_eq!(misc.tcg_date.as_deref(), Some("2002-03-08"));
+        assert_eq!(misc.konami_id, Some(4844));
+        assert!(misc.has_effect);
+
+        let round_trip: Card =
+            serde_json::from_value(serde_json::to_value(&spell).unwrap()).unwrap();
+        assert_eq!(round_trip.info().misc.as_ref().unwrap().views, 1000);
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert!(spell.info().misc.is_none());
+    }
+
+    #[test]
+    fn max_control() {
+        let link = card(
+            "link",
+            serde_json::json!({
+                "desc": "2+ monsters with different names, except Tokens\r\nYou can only control 1 \"Apollousa, Bow of the Goddess\". The original ATK of this card becomes 800 x the number of Link Materials used for its Link Summon.",
+                "type": "Link Monster",
+                "race": "Fairy",
+                "attribute": "WIND",
+                "atk": -1,
+                "linkval": 4,
+                "linkmarkers": ["Top", "Bottom-Left", "Bottom", "Bottom-Right"]
+            }),
+        );
+        assert_eq!(link.max_control(), Some(1));
+
+        let spell = card(
+            "spell",
+            serde_json::json!({ "desc": "Draw 2 cards.", "race": "Normal" }),
+        );
+        assert_eq!(spell.max_control(), None);
+    }
+
+    #[test]
+    fn price_deltas() {
+        let snapshot = |prices: [&str; 2]| {
+            card(
+                "spell",
+                serde_json::json!({
+                    "race": "Normal",
+                    "card_sets": [
+                        {
+                            "set_name": "Legend of Blue Eyes White Dragon",
+                            "set_code": "LOB-EN119",
+                            "set_rarity": "Rare",
+                            "set_rarity_code": "(R)",
+                            "set_price": prices[0]
+                        },
+                        {
+                            "set_name": "Starter Deck: Yugi",
+                            "set_code": "SDY-EN040",
+                            "set_rarity": "Common",
+                            "set_rarity_code": "(C)",
+                            "set_price": prices[1]
+                        }
+                    ]
+                }),
+            )
+        };
+
+        let deltas = super::price_deltas(&snapshot(["10.00", "1.50"]), &snapshot(["12.5", "1"]));
+        assert_eq!(
+            deltas,
+            vec![
+                ("LOB-EN119".to_string(), 2.5),
+                ("SDY-EN040".to_string(), -0.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn unique_images() {
+        let spell = card(
+            "spell",
+            serde_json::json!({
+                "race": "Normal",
+                "card_images": [card_image_json(1), card_image_json(2), card_image_json(1)]
+            }),
+        );
+        let ids: Vec<_> = spell.unique_images().iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn image_selection() {
+        let dark_magician = card(
+            "normal",
+            serde_json::json!({
+                "id": 46986414,
+                "type": "Normal Monster",
+                "race": "Spellcaster",
+                "attribute": "DARK",
+                "level": 7,
+                "atk": 2500,
+                "def": 2100,
+                "card_images": [
+                    card_image_json(36996508),
+                    card_image_json(46986414),
+                    card_image_json(38033121)
+                ]
+            }),
+        );
+        let info = dark_magician.info();
+
+        assert_eq!(info.primary_image().map(|i| i.id), Some(46986414));
+        assert_eq!(
+            info.image_for_id(38033121).map(|i| i.url.as_str()),
+            Some("https://images.ygoprodeck.com/images/cards/38033121.jpg")
+        );
+        assert!(info.image_for_id(1).is_none());
+
+        let spell = card(
+            "spell",
+            serde_json::json!({
+                "race": "Normal",
+                "card_images": [card_image_json(5), card_image_json(6)]
+            }),
+        );
+        assert_eq!(spell.info().primary_image().map(|i| i.id), Some(5));
+    }
+
+    #[test]
+    fn konami_db_url() {
+        let spell = card(
+            "spell",
+            serde_json::json!({ "race": "Normal", "misc_info": [{ "konami_id": 4844 }] }),
+        );
+        assert_eq!(
+            spell.konami_db_url().as_deref(),
+            Some("https://www.db.yugioh-card.com/yugiohdb/card_search.action?ope=2&cid=4844")
+        );
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert_eq!(spell.konami_db_url(), None);
+    }
+
+    #[test]
+    fn stat_total() {
+        let monster = |frame_type: &str, atk: i32, def: Option<i32>| {
+            let mut fields = serde_json::json!({
+                "type": "Effect Monster",
+                "race": "Dragon",
+                "attribute": "DARK",
+                "atk": atk,
+                "level": 4,
+                "linkval": 2,
+                "linkmarkers": ["Top", "Bottom"]
+            });
+            if let Some(def) = def {
+                fields["def"] = def.into();
+            }
+            card(frame_type, fields)
+        };
+
+        assert_eq!(monster("effect", 1500, Some(1200)).stat_total(), Some(2700));
+        assert_eq!(monster("effect", -1, Some(1000)).stat_total(), Some(1000));
+        assert_eq!(monster("link", 2300, None).stat_total(), Some(2300));
+        assert_eq!(monster("token", 1000, Some(1000)).stat_total(), Some(2000));
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert_eq!(spell.stat_total(), None);
+    }
+
+    #[test]
+    fn info_accessors() {
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert_eq!(spell.name(), "Test Card");
+        assert_eq!(spell.id(), CardId(1));
+
+        let token = card(
+            "token",
+            serde_json::json!({
+                "race": "Beast",
+                "attribute": "EARTH",
+                "atk": 0,
+                "def": 0
+            }),
+        );
+        assert_eq!(token.name(), "Test Card");
+        assert_eq!(token.type_name(), "Token");
+    }
+
+    #[test]
+    fn pendulum_parts() {
+        let pendulum = |fields: serde_json::Value| {
+            let mut fields = fields;
+            fields.as_object_mut().unwrap().extend(
+                serde_json::json!({
+                    "type": "Pendulum Effect Monster",
+                    "race": "Spellcaster",
+                    "attribute": "DARK",
+                    "atk": 1200,
+                    "def": 0,
+                    "level": 4,
+                    "scale": 1
+                })
+                .as_object()
+                .unwrap()
+                .clone(),
+            );
+            card("effect_pendulum", fields)
+        };
+
+        let combined = pendulum(serde_json::json!({
+            "desc": "[ Pendulum Effect ]\r\nOnce per turn: You can draw 1 card.\r\n----------------------------------------\r\n[ Monster Effect ]\r\nCannot be destroyed by battle."
+        }));
+        assert_eq!(
+            combined.pendulum_parts(),
+            Some((
+                "Once per turn: You can draw 1 card.".to_string(),
+                "Cannot be destroyed by battle.".to_string()
+            ))
+        );
+
+        let Card::Pendulum(ref m) = combined else {
+            panic!("expected a pendulum monster");
+        };
+        assert_eq!(m.pend_desc, None);
+        assert_eq!(m.monster_desc, None);
+
+        let split = pendulum(serde_json::json!({
+            "desc": "ignored",
+            "pend_desc": "Once per turn: You can draw 1 card.",
+            "monster_desc": "Cannot be destroyed by battle."
+        }));
+        assert_eq!(split.pendulum_parts(), combined.pendulum_parts());
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert_eq!(spell.pendulum_parts(), None);
+    }
+
+    #[test]
+    fn typeline() {
+        let tuner = card(
+            "effect",
+            serde_json::json!({
+                "type": "Tuner Monster",
+                "typeline": ["Spellcaster", "Tuner", "Effect"],
+                "race": "Spellcaster",
+                "attribute": "LIGHT",
+                "atk": 300,
+                "def": 100,
+                "level": 1
+            }),
+        );
+        assert!(tuner.info().typeline.iter().any(|t| t == "Tuner"));
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert!(spell.info().typeline.is_empty());
+    }
+
+    #[test]
+    fn card_id_round_trip() {
+        let id: CardId = "89631139".parse().unwrap();
+        assert_eq!(id, CardId(89631139));
+        assert_eq!(id.to_string(), "89631139");
+        assert_eq!(id.to_string().parse::<CardId>(), Ok(id));
+
+        assert!("Blue-Eyes".parse::<CardId>().is_err());
+        assert!("".parse::<CardId>().is_err());
+        assert!("-1".parse::<CardId>().is_err());
+    }
+
+    #[test]
+    fn copies_allowed() {
+        let spell = |fields: serde_json::Value| {
+            let mut fields = fields;
+            fields["race"] = "Normal".into();
+            card("spell", fields)
+        };
+
+        let pot = spell(serde_json::json!({
+            "banlist_info": { "ban_tcg": "Banned", "ban_goat": "Limited" },
+            "misc_info": [{ "formats": ["TCG", "OCG", "GOAT"] }]
+        }));
+        assert_eq!(pot.copies_allowed(Format::Tcg, Banlist::Tcg), 0);
+        assert_eq!(pot.copies_allowed(Format::Goat, Banlist::Goat), 1);
+        assert_eq!(pot.copies_allowed(Format::Ocg, Banlist::Ocg), 3);
+        assert_eq!(pot.copies_allowed(Format::MasterDuel, Banlist::Tcg), 0);
+
+        let semi = spell(serde_json::json!({
+            "banlist_info": { "ban_ocg": "Semi-Limited" }
+        }));
+        assert_eq!(semi.copies_allowed(Format::Ocg, Banlist::Ocg), 2);
+        assert_eq!(semi.copies_allowed(Format::Tcg, Banlist::Tcg), 3);
+    }
+
+    #[test]
+    fn clone() {
+        let monster = card(
+            "effect",
+            serde_json::json!({
+                "type": "Effect Monster",
+                "race": "Warrior",
+                "attribute": "EARTH",
+                "atk": 1800,
+                "def": 1000,
+                "level": 4,
+                "card_sets": [{
+                    "set_name": "Legend of Blue Eyes White Dragon",
+                    "set_code": "LOB-001",
+                    "set_rarity": "Common",
+                    "set_rarity_code": "(C)",
+                    "set_price": "1.00"
+                }]
+            }),
+        );
+        let copy = monster.clone();
+
+        let (Card::Effect(original), Card::Effect(copy)) = (&monster, &copy) else {
+            panic!("expected effect monsters");
+        };
+        assert_eq!(copy.info.id, original.info.id);
+        assert_eq!(copy.info.name, original.info.name);
+        assert_eq!(copy.info.sets[0].code, original.info.sets[0].code);
+        assert_eq!(copy.race, original.race);
+        assert_eq!(copy.attribute, original.attribute);
+        assert_eq!(
+            (copy.atk, copy.def, copy.level),
+            (StatValue::Known(1800), StatValue::Known(1000), 4)
+        );
+    }
+
+    #[test]
+    fn eq() {
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        let trap = card("trap", serde_json::json!({ "race": "Normal" }));
+        let mut renamed = spell.clone();
+        if let Card::Spell(s) = &mut renamed {
+            s.info.name = "Renamed".to_string();
+        }
+
+        assert_eq!(spell, spell.clone());
+        assert_ne!(spell, trap);
+        assert_ne!(spell, renamed);
+    }
+
+    #[test]
+    fn stat_value() {
+        let stat = |value: serde_json::Value| serde_json::from_value::<StatValue>(value).unwrap();
+
+        assert_eq!(stat(serde_json::json!(2500)), StatValue::Known(2500));
+        assert_eq!(stat(serde_json::json!(0)), StatValue::Known(0));
+        assert_eq!(stat(serde_json::json!(-1)), StatValue::Unknown);
+        assert_eq!(stat(serde_json::json!("?")), StatValue::Unknown);
+        assert_eq!(stat(serde_json::json!("1800")), StatValue::Known(1800));
+        assert!(serde_json::from_value::<StatValue>(serde_json::json!("abc")).is_err());
+
+        assert_eq!(StatValue::Known(2500).value(), Some(2500));
+        assert_eq!(StatValue::Unknown.value(), None);
+        assert_eq!(StatValue::Unknown.to_string(), "?");
+        assert_eq!(serde_json::to_value(StatValue::Unknown).unwrap(), -1);
+    }
+
+    #[test]
+    fn rarity() {
+        let printing = |code: &str| -> CardSet {
+            serde_json::from_value(serde_json::json!({
+                "set_name": "Legend of Blue Eyes White Dragon",
+                "set_code": "LOB-001",
+                "set_rarity": "Secret Rare",
+                "set_rarity_code": code,
+                "set_price": "12.5"
+            }))
+            .unwrap()
+        };
+
+        let secret = printing("(ScR)");
+        assert_eq!(secret.rarity_code, Rarity::SecretRare);
+        assert_eq!(secret.rarity, "Secret Rare");
+        assert_eq!(secret.price_value(), Some(12.5));
+        assert_eq!(
+            printing("(QCScR)").rarity_code,
+            Rarity::QuarterCenturySecretRare
+        );
+
+        let unknown = printing("(XYZR)");
+        assert_eq!(unknown.rarity_code, Rarity::Other("(XYZR)".to_string()));
+        assert_eq!(printing("").rarity_code, Rarity::Other(String::new()));
+
+        let json = serde_json::to_value(&unknown).unwrap();
+        assert_eq!(json["set_rarity_code"], "(XYZR)");
+        assert_eq!(
+            serde_json::to_value(&secret).unwrap()["set_rarity_code"],
+            "(ScR)"
+        );
+    }
+
+    #[test]
+    fn as_monster() {
+        let effect = card(
+            "effect",
+            serde_json::json!({
+                "type": "Effect Monster",
+                "race": "Spellcaster",
+                "attribute": "DARK",
+                "level": 7,
+                "atk": 2500,
+                "def": 2100
+            }),
+        );
+        let monster = effect.as_monster().unwrap();
+        assert_eq!(monster.atk(), StatValue::Known(2500));
+        assert_eq!(monster.def(), Some(StatValue::Known(2100)));
+        assert_eq!((monster.level(), monster.rank()), (Some(7), None));
+        assert_eq!(monster.race(), &MonsterRace::Spellcaster);
+        assert_eq!(monster.attribute(), &Attribute::Dark);
+        assert_eq!(monster.card_type(), Some(&MonsterType::EffectMonster));
+        assert_eq!((monster.link_markers(), monster.scale()), (&[][..], None));
+
+        let xyz = card(
+            "xyz",
+            serde_json::json!({
+                "type": "XYZ Monster",
+                "race": "Warrior",
+                "attribute": "LIGHT",
+                "level": 4,
+                "atk": 2500,
+                "def": 1500
+            }),
+        );
+        let monster = xyz.as_monster().unwrap();
+        assert_eq!((monster.level(), monster.rank()), (None, Some(4)));
+
+        let xyz_pendulum = card(
+            "xyz_pendulum",
+            serde_json::json!({
+                "type": "XYZ Pendulum Effect Monster",
+                "race": "Dragon",
+                "attribute": "DARK",
+                "level": 4,
+                "scale": 1,
+                "atk": 2500,
+                "def": 2000
+            }),
+        );
+        let monster = xyz_pendulum.as_monster().unwrap();
+        assert_eq!((monster.level(), monster.rank()), (None, Some(4)));
+        assert_eq!(monster.scale(), Some(1));
+
+        let link = card(
+            "link",
+            serde_json::json!({
+                "type": "Link Monster",
+                "race": "Fairy",
+                "attribute": "WIND",
+                "atk": -1,
+                "linkval": 4,
+                "linkmarkers": ["Top"]
+            }),
+        );
+        let monster = link.as_monster().unwrap();
+        assert_eq!(monster.atk(), StatValue::Unknown);
+        assert_eq!(monster.def(), None);
+        assert_eq!((monster.level(), monster.link_rating()), (None, Some(4)));
+        assert_eq!(monster.link_markers(), &[LinkMarker::Top]);
+
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert!(spell.as_monster().is_none());
+    }
+
+    #[test]
+    fn category() {
+        let monster_fields = serde_json::json!({
+            "type": "Effect Monster",
+            "race": "Dragon",
+            "attribute": "DARK",
+            "level": 4,
+            "atk": 1000,
+            "def": 1000,
+            "scale": 4,
+            "linkval": 2,
+            "linkmarkers": ["Top", "Bottom"]
+        });
+
+        for frame_type in [
+            "normal",
+            "effect",
+            "ritual",
+            "fusion",
+            "synchro",
+            "xyz",
+            "link",
+            "normal_pendulum",
+            "effect_pendulum",
+            "ritual_pendulum",
+            "fusion_pendulum",
+            "synchro_pendulum",
+            "xyz_pendulum",
+        ] {
+            let monster = card(frame_type, monster_fields.clone());
+            assert_eq!(monster.category(), CardCategory::Monster, "{frame_type}");
+        }
+
+        let race = serde_json::json!({ "race": "Normal" });
+        assert_eq!(card("spell", race.clone()).category(), CardCategory::Spell);
+        assert_eq!(card("trap", race.clone()).category(), CardCategory::Trap);
+        assert_eq!(card("skill", race).category(), CardCategory::Skill);
+        assert_eq!(
+            card("token", monster_fields).category(),
+            CardCategory::Token
+        );
+    }
+
+    #[test]
+    fn parse_enums() {
+        for attribute in [Attribute::Light, Attribute::Dark, Attribute::Divine] {
+            assert_eq!(attribute.to_string().parse(), Ok(attribute));
+        }
+        for race in [
+            MonsterRace::BeastWarrior,
+            MonsterRace::CreatorGod,
+            MonsterRace::SeaSerpent,
+            MonsterRace::WingedBeast,
+        ] {
+            assert_eq!(race.to_string().parse(), Ok(race));
+        }
+        assert_eq!(
+            SpellRace::QuickPlay.to_string().parse(),
+            Ok(SpellRace::QuickPlay)
+        );
+        assert_eq!(TrapRace::try_from("Counter"), Ok(TrapRace::Counter));
+
+        let error = "dark".parse::<Attribute>().unwrap_err();
+        assert_eq!(error.to_string(), "unknown attribute `dark`");
+        assert!(MonsterRace::try_from("Dragons").is_err());
+    }
+
+    #[test]
+    fn monster_type_flags() {
+        use MonsterType::*;
+
+        let all = [
+            EffectMonster,
+            FlipEffectMonster,
+            FlipTunerEffectMonster,
+            GeminiMonster,
+            NormalMonster,
+            NormalTunerMonster,
+            PendulumEffectMonster,
+            PendulumEffectRitualMonster,
+            PendulumFlipEffectMonster,
+            PendulumNormalMonster,
+            PendulumTunerEffectMonster,
+            RitualEffectMonster,
+            RitualMonster,
+            SpiritMonster,
+            ToonMonster,
+            TunerMonster,
+            UnionEffectMonster,
+            FusionMonster,
+            LinkMonster,
+            PendulumEffectFusionMonster,
+            SynchroMonster,
+            SynchroPendulumEffectMonster,
+            SynchroTunerMonster,
+            XYZMonster,
+            XYZPendulumEffectMonster,
+            Token,
+        ];
+        let with = |flag: fn(&MonsterType) -> bool| {
+            all.iter()
+                .filter(|t| flag(t))
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        // every type whose name says Tuner, and only those
+        for monster_type in &all {
+            assert_eq!(
+                monster_type.is_tuner(),
+                monster_type.to_string().contains("Tuner"),
+                "{monster_type}"
+            );
+            assert_eq!(
+                monster_type.is_pendulum(),
+                monster_type.to_string().contains("Pendulum"),
+                "{monster_type}"
+            );
+        }
+        assert_eq!(
+            with(MonsterType::is_flip),
+            [
+                "Flip Effect Monster",
+                "Flip Tuner Effect Monster",
+                "Pendulum Flip Effect Monster"
+            ]
+        );
+        assert!(PendulumTunerEffectMonster.is_effect());
+        assert!(ToonMonster.is_effect() && ToonMonster.is_toon());
+        assert!(!NormalTunerMonster.is_effect());
+        assert!(!SynchroMonster.is_effect());
+        assert_eq!(with(MonsterType::is_gemini), ["Gemini Monster"]);
+        assert_eq!(with(MonsterType::is_spirit), ["Spirit Monster"]);
+        assert_eq!(with(MonsterType::is_union), ["Union Effect Monster"]);
+    }
+
+    #[test]
+    fn spell_and_trap_race_display() {
+        let spell_races = [
+            (SpellRace::Normal, "Normal"),
+            (SpellRace::Field, "Field"),
+            (SpellRace::Equip, "Equip"),
+            (SpellRace::Continuous, "Continuous"),
+            (SpellRace::QuickPlay, "Quick-Play"),
+            (SpellRace::Ritual, "Ritual"),
+        ];
+        for (race, api) in spell_races {
+            assert_eq!(race.to_string(), api);
+            assert_eq!(serde_json::to_value(&race).unwrap(), api);
+        }
+
+        let trap_races = [
+            (TrapRace::Normal, "Normal"),
+            (TrapRace::Continuous, "Continuous"),
+            (TrapRace::Counter, "Counter"),
+        ];
+        for (race, api) in trap_races {
+            assert_eq!(race.to_string(), api);
+            assert_eq!(serde_json::to_value(&race).unwrap(), api);
+        }
+    }
+
+    #[test]
+    fn md_rarity() {
+        let spell = |misc: serde_json::Value| {
+            card(
+                "spell",
+                serde_json::json!({ "race": "Normal", "misc_info": [misc] }),
+            )
+        };
+
+        let pot = spell(serde_json::json!({ "md_rarity": "Ultra Rare" }));
+        let misc = pot.info().misc.as_ref().unwrap();
+        assert_eq!(misc.md_rarity, Some(MasterDuelRarity::UltraRare));
+
+        let common = spell(serde_json::json!({ "md_rarity": "Common" }));
+        assert_eq!(
+            common.info().misc.as_ref().unwrap().md_rarity,
+            Some(MasterDuelRarity::Normal)
+        );
+
+        let unrecognized = spell(serde_json::json!({ "md_rarity": "" }));
+        assert_eq!(
+            unrecognized.info().misc.as_ref().unwrap().md_rarity,
+            Some(MasterDuelRarity::Other(String::new()))
+        );
+
+        let unreleased = spell(serde_json::json!({ "views": 1 }));
+        assert_eq!(unreleased.info().misc.as_ref().unwrap().md_rarity, None);
+    }
+
+    #[test]
+    fn group_by_archetype() {
+        let spell = |id: u64, archetype: Option<&str>| {
+            let mut fields = serde_json::json!({ "id": id, "race": "Normal" });
+            if let Some(archetype) = archetype {
+                fields["archetype"] = archetype.into();
+            }
+            card("spell", fields)
+        };
+
+        let groups = super::group_by_archetype(vec![
+            spell(1, Some("Blue-Eyes")),
+            spell(2, None),
+            spell(3, Some("Dark Magician")),
+            spell(4, Some("Blue-Eyes")),
+        ]);
+        let ids = |archetype: Option<&str>| -> Vec<u64> {
+            groups[&archetype.map(str::to_string)]
+                .iter()
+                .map(|c| c.id().0)
+                .collect()
+        };
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(ids(Some("Blue-Eyes")), [1, 4]);
+        assert_eq!(ids(Some("Dark Magician")), [3]);
+        assert_eq!(ids(None), [2]);
+    }
+
+    #[test]
+    fn extra_fields() {
+        let monster = card(
+            "effect",
+            serde_json::json!({
+                "type": "Effect Monster",
+                "race": "Dragon",
+                "attribute": "DARK",
+                "level": 4,
+                "atk": 1000,
+                "def": 1000,
+                "genesys_points": 25
+            }),
+        );
+        let extra = &monster.info().extra;
+        assert_eq!(extra.get("genesys_points"), Some(&serde_json::json!(25)));
+        // fields read by the monster struct itself aren't duplicated
+        assert!(!extra.contains_key("race"));
+        assert!(!extra.contains_key("name"));
+
+        let round_trip: Card =
+            serde_json::from_value(serde_json::to_value(&monster).unwrap()).unwrap();
+        assert_eq!(round_trip, monster);
+    }
+
+    #[test]
+    fn level_or_rank() {
+        let monster = |frame_type: &str, card_type: &str| {
+            card(
+                frame_type,
+                serde_json::json!({
+                    "type": card_type,
+                    "race": "Warrior",
+                    "attribute": "EARTH",
+                    "level": 4,
+                    "atk": 1800,
+                    "def": 1200,
+                    "scale": 4
+                }),
+            )
+        };
+
+        assert_eq!(
+            monster("normal", "Normal Monster").level_or_rank(),
+            Some((LevelKind::Level, 4))
+        );
+        assert_eq!(
+            monster("xyz", "XYZ Monster").level_or_rank(),
+            Some((LevelKind::Rank, 4))
+        );
+        assert_eq!(
+            monster("effect_pendulum", "Pendulum Effect Monster").level_or_rank(),
+            Some((LevelKind::Level, 4))
+        );
+        assert_eq!(
+            monster("xyz_pendulum", "XYZ Pendulum Effect Monster").level_or_rank(),
+            Some((LevelKind::Rank, 4))
+        );
+
+        let link = card(
+            "link",
+            serde_json::json!({
+                "type": "Link Monster",
+                "race": "Cyberse",
+                "attribute": "DARK",
+                "linkval": 2,
+                "linkmarkers": ["Left", "Right"],
+                "atk": 1200
+            }),
+        );
+        assert_eq!(link.level_or_rank(), None);
+        let spell = card("spell", serde_json::json!({ "race": "Normal" }));
+        assert_eq!(spell.level_or_rank(), None);
+    }
+
+    #[test]
+    fn sort_keys() {
+        let monster = |name: &str, level: u8, atk: i32, def: i32| {
+            card(
+                "normal",
+                serde_json::json!({
+                    "name": name,
+                    "type": "Normal Monster",
+                    "race": "Warrior",
+                    "attribute": "EARTH",
+                    "level": level,
+                    "atk": atk,
+                    "def": def
+                }),
+            )
+        };
+        let mut cards = vec![
+            monster("b", 4, 1800, 1200),
+            monster("Unknown", 8, -1, -1),
+            card(
+                "spell",
+                serde_json::json!({ "name": "Axe", "race": "Equip" }),
+            ),
+            monster("C", 7, 2500, 2100),
+        ];
+        let names = |cards: &[Card]| {
+            cards
+                .iter()
+                .map(|c| c.name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        cards.sort_by(Card::by_atk_desc);
+        assert_eq!(names(&cards)[..2], ["C", "b"]);
+        cards.sort_by_key(Card::by_atk);
+        assert_eq!(names(&cards)[2..], ["b", "C"]);
+
+        cards.sort_by(Card::by_def_desc);
+        assert_eq!(names(&cards)[..2], ["C", "b"]);
+        cards.sort_by(Card::by_level_desc);
+        assert_eq!(names(&cards), ["Unknown", "C", "b", "Axe"]);
+        cards.sort_by_key(Card::by_level);
+        assert_eq!(names(&cards), ["Axe", "b", "C", "Unknown"]);
+
+        cards.sort_by_cached_key(Card::by_name);
+        assert_eq!(names(&cards), ["Axe", "b", "C", "Unknown"]);
+    }
+
+    #[test]
+    fn display() {
+        let trent = card(
+            "normal",
+            serde_json::json!({
+                "name": "Trent",
+                "type": "Normal Monster",
+                "humanReadableCardType": "Normal Monster",
+                "race": "Plant",
+                "attribute": "EARTH",
+                "level": 5,
+                "atk": 1500,
+                "def": 1800
+            }),
+        );
+        assert_eq!(
+            trent.to_string(),
+            "Trent [Normal Monster] EARTH/Plant ★5 1500/1800"
+        );
+
+        let xyz = card(
+            "xyz",
+            serde_json::json!({
+                "type": "XYZ Monster",
+                "race": "Machine",
+                "attribute": "LIGHT",
+                "level": 4,
+                "atk": -1,
+                "def": 0
+            }),
+        );
+        assert_eq!(
+            xyz.to_string(),
+            "Test Card [XYZ Monster] LIGHT/Machine ☆4 ?/0"
+        );
+
+        let xyz_pendulum = card(
+            "xyz_pendulum",
+            serde_json::json!({
+                "type": "XYZ Pendulum Effect Monster",
+                "humanReadableCardType": "XYZ Pendulum Effect Monster",
+                "race": "Dragon",
+                "attribute": "DARK",
+                "level": 4,
+                "scale": 1,
+                "atk": 2500,
+                "def": 2000
+            }),
+        );
+        assert_eq!(
+            xyz_pendulum.to_string(),
+            "Test Card [XYZ Pendulum Effect Monster] DARK/Dragon ☆4 2500/2000"
+        );
+
+        let link = card(
+            "link",
+            serde_json::json!({
+                "type": "Link Monster",
+                "race": "Cyberse",
+                "attribute": "DARK",
+                "linkval": 2,
+                "linkmarkers": ["Left", "Right"],
+                "atk": 1200
+            }),
+        );
+        assert_eq!(
+            link.to_string(),
+            "Test Card [Link Monster] DARK/Cyberse LINK-2 1200"
+        );
+
+        let spell = card(
+            "spell",
+            serde_json::json!({
+                "type": "Spell Card",
+                "humanReadableCardType": "Normal Spell",
+                "race": "Normal"
+            }),
+        );
+        assert_eq!(spell.to_string(), "Test Card [Normal Spell]");
+    }
+
+    #[test]
+    fn unknown_frame_type() {
+        let unknown = card(
+            "newthing",
+            serde_json::json!({ "type": "New Thing", "power": 9 }),
+        );
+        let Card::Unknown(ref u) = unknown else {
+            panic!("expected an unknown card, got {unknown:?}");
+        };
+        assert_eq!(u.frame_type, "newthing");
+        assert_eq!(u.raw["power"], 9);
+        assert_eq!(u.info.extra.get("power"), Some(&serde_json::json!(9)));
+        assert!(!u.info.extra.contains_key("frameType"));
+        assert_eq!(unknown.name(), "Test Card");
+        assert_eq!(unknown.category(), CardCategory::Unknown);
+        assert_eq!(unknown.type_name(), "New Thing");
+        assert!(unknown.as_monster().is_none());
+
+        let round_trip: Card =
+            serde_json::from_value(serde_json::to_value(&unknown).unwrap()).unwrap();
+        assert_eq!(round_trip, unknown);
+
+        // a known frame type with bad fields is still an error
+        let malformed = serde_json::json!({
+            "id": 1,
+            "name": "Test Card",
+            "desc": "",
+            "frameType": "effect",
+            "humanReadableCardType": "",
+            "ygoprodeck_url": "",
+            "card_images": [],
+            "race": "Dragon"
+        });
+        let error = serde_json::from_value::<Card>(malformed).unwrap_err();
+        assert!(error.to_string().contains("missing field"), "{error}");
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let ids: HashSet<CardId> = [1, 2, 1, 3, 2].map(CardId).into_iter().collect();
+        assert_eq!(ids.len(), 3);
+
+        let spell = |id: u64| card("spell", serde_json::json!({ "id": id, "race": "Normal" }));
+        let cards: HashSet<Card> = [spell(1), spell(2), spell(1)].into_iter().collect();
+        assert_eq!(cards.len(), 2);
+        assert!(cards.contains(&spell(2)));
+    }
+
+    #[test]
+    fn tcgplayer_data() {
+        let printing = |extra: serde_json::Value| -> CardSet {
+            let mut set = serde_json::json!({
+                "set_name": "Legend of Blue Eyes White Dragon",
+                "set_code": "LOB-001",
+                "set_rarity": "Ultra Rare",
+                "set_rarity_code": "(UR)",
+                "set_price": "0"
+            });
+            set.as_object_mut()
+                .unwrap()
+                .extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(set).unwrap()
+        };
+
+        let plain = printing(serde_json::json!({}));
+        assert_eq!(
+            (plain.url.as_deref(), plain.edition.as_deref()),
+            (None, None)
+        );
+        assert_eq!(plain.tcgplayer_product_id(), None);
+
+        let tcgplayer = printing(serde_json::json!({
+            "set_url": "https://www.tcgplayer.com/product/21736/yugioh-legend-of-blue-eyes-white-dragon-blue-eyes-white-dragon?utm_campaign=affiliate",
+            "set_edition": "Unlimited"
+        }));
+        assert_eq!(tcgplayer.edition.as_deref(), Some("Unlimited"));
+        assert_eq!(tcgplayer.tcgplayer_product_id(), Some(21736));
     }
 }
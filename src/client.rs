@@ -1,427 +1,3094 @@
-use std::fmt::Display;
+use std::{fmt::Display, path::Path, sync::Mutex, time::Duration};
 
-use serde::Deserialize;
+use chrono::NaiveDate;
+use futures::{Stream, StreamExt, future, stream};
+use serde::{Deserialize, de::DeserializeOwned};
 
-use crate::{card::Card, request::Request};
+use crate::{
+    cache::LruCache,
+    card::{Card, CardId, CardSet},
+    deck::{ResolvedDeck, Ydk},
+    rate_limit::RateLimiter,
+    request::{Request, RequestBuilder},
+    set::{SetCardInfo, SetInfo},
+};
+
+/// Base URL of the production YGOProDeck API.
+pub const DEFAULT_BASE_URL: &str = "https://db.ygoprodeck.com/api/v7";
 
 #[derive(Debug)]
 pub struct Client {
     client: reqwest::Client,
+    base_url: String,
+    /// Applied to requests that don't set their own, see
+    /// [`Client::with_timeout`].
+    timeout: Option<Duration>,
+    retries: u32,
+    /// Cards served from memory instead of the API, see
+    /// [`Client::from_cached_json`].
+    offline: Option<Vec<Card>>,
+    /// Recent `cardinfo.php` results, see [`Client::with_cache`].
+    cache: Option<Mutex<LruCache>>,
+    /// Throttles outgoing requests, see [`Client::with_rate_limit`].
+    rate_limit: Option<RateLimiter>,
 }
 
 impl Client {
     pub fn new() -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL)
+    }
+
+    /// Creates a client that sends its requests to `base` instead of the
+    /// production API, e.g. a mock server or a caching proxy.
+    ///
+    /// `base` is the part of the URL before the endpoint name, so
+    /// `cardinfo.php` is requested as `{base}/cardinfo.php`.
+    pub fn with_base_url(base: impl Into<String>) -> Self {
+        Self::from_reqwest(reqwest::Client::new()).base_url(base)
+    }
+
+    /// Creates a client that sends its requests through `client`.
+    ///
+    /// A `reqwest::Client` holds a connection pool, so an application making
+    /// many `trent` clients, or also talking to other services, can share
+    /// one instead of opening new connections for each. Its proxy, TLS and
+    /// default header settings apply to the API requests too.
+    pub fn from_reqwest(client: reqwest::Client) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: None,
+            retries: 0,
+            offline: None,
+            cache: None,
+            rate_limit: None,
         }
     }
 
+    /// Creates a client that answers queries from a local copy of the whole
+    /// card database, without any network access.
+    ///
+    /// `path` must hold a full `cardinfo.php` dump, i.e. the `{"data": [...]}`
+    /// response of a request without filters. [`Client::get`] and
+    /// [`Client::get_by_name`] then evaluate their filters in memory; names,
+    /// types, races, attributes, ATK/DEF, levels, link values and markers,
+    /// scales and card sets are supported, while release dates and sorting
+    /// are ignored.
+    pub fn from_cached_json(path: &Path) -> Result<Self, Error> {
+        let bytes = std::fs::read(path).map_err(Error::Io)?;
+        let dump: ApiResponse = serde_json::from_slice(&bytes).map_err(Error::Deserialization)?;
+
+        Ok(Self {
+            offline: Some(dump.data),
+            ..Self::new()
+        })
+    }
+
+    /// Sends the requests to `base` instead of the production API, like
+    /// [`Client::with_base_url`], e.g. for a client made with
+    /// [`Client::from_reqwest`].
+    pub fn base_url(mut self, base: impl Into<String>) -> Self {
+        self.base_url = normalize_base_url(base.into());
+        self
+    }
+
+    /// Bounds every request made by this client to `timeout`, covering the
+    /// whole exchange from connecting until the body has been read.
+    ///
+    /// By default there is no timeout, other than the one of a client passed
+    /// to [`Client::from_reqwest`]. A request that runs out of time fails
+    /// with [`Error::Network`], for which [`reqwest::Error::is_timeout`]
+    /// returns `true`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retries a failed request up to `max` times, waiting twice as long
+    /// before each attempt as before the previous one.
+    ///
+    /// Only transient failures are retried: network errors, rate limiting
+    /// (`429`) and server errors (`5xx`). When rate limited, the client waits
+    /// at least as long as the API's `Retry-After` header asks. Rejected
+    /// queries and [`Error::NotFound`] are returned right away. By default
+    /// requests are not retried.
+    pub fn with_retries(mut self, max: u32) -> Self {
+        self.retries = max;
+        self
+    }
+
+    /// Keeps the results of the last `capacity` distinct card queries in
+    /// memory, so repeating a query doesn't hit the network again.
+    ///
+    /// Queries are keyed by their final URL and only successful results are
+    /// cached. The cache never expires entries on its own, so a long-lived
+    /// client won't see price or banlist updates for cached cards.
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.cache = Some(Mutex::new(LruCache::new(capacity)));
+        self
+    }
+
+    /// Sends at most `per_second` requests per second, on average.
+    ///
+    /// YGOProDeck temporarily blocks clients going over 20 requests per
+    /// second. Requests are throttled with a token bucket: up to `per_second`
+    /// of them go out at once, then the next ones, concurrent or not, wait
+    /// their turn. Retries and image downloads count as requests too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `per_second` is 0.
+    pub fn with_rate_limit(mut self, per_second: u32) -> Self {
+        assert!(per_second > 0, "the rate limit must allow some requests");
+        self.rate_limit = Some(RateLimiter::new(per_second));
+        self
+    }
+
     pub async fn get<'a>(&self, request: Request<'a>) -> Result<Vec<Card>, Error> {
-        let response = self
-            .client
-            .get(format!(
-                "https://db.ygoprodeck.com/api/v7/cardinfo.php?{}",
-                request.to_url_params()
-            ))
-            .send()
-            .await
-            .map_err(|e| Error::Network(e))?;
+        let mut cards = self.fetch(&request).await?;
+        cards.retain(|c| request.post_filter(c));
 
-        if response.status() == 400 {
-            return Err(Error::NotFound);
+        Ok(cards)
+    }
+
+    /// Like [`Client::get`], but reads each card of the response on its own,
+    /// so that a card the crate can't read (e.g. with a field in a shape the
+    /// API didn't use before) is skipped instead of failing the whole
+    /// request. Cards of an unknown frame type are already kept as
+    /// [`Card::Unknown`] by both methods.
+    ///
+    /// Returns the cards that could be read, along with the entries that
+    /// couldn't. Lenient results are never cached.
+    pub async fn get_lenient(
+        &self,
+        request: Request<'_>,
+    ) -> Result<(Vec<Card>, Vec<SkippedCard>), Error> {
+        if self.offline.is_some() {
+            return Ok((self.get(request).await?, Vec::new()));
         }
 
-        let json = response
-            .json::<ApiResponse>()
-            .await
-            .map_err(|_| Error::Deserialization)?;
+        request.validate()?;
+        let url = format!("{}/cardinfo.php?{}", self.base_url, request.to_url_params());
+        let response = self.send_with_timeout(&url, request.timeout()).await?;
+        let status = response.status().as_u16();
+        let json: ApiResponse<serde_json::Value> = parse_json(response).await?;
+
+        let mut cards = Vec::new();
+        let mut skipped = Vec::new();
+        for entry in json.into_cards(status)? {
+            let id = entry.get("id").and_then(|id| id.as_u64()).map(CardId);
+            let name = entry
+                .get("name")
+                .and_then(|name| name.as_str())
+                .map(str::to_string);
+
+            match serde_json::from_value::<Card>(entry) {
+                Ok(card) if request.post_filter(&card) => cards.push(card),
+                Ok(_) => {}
+                Err(error) => skipped.push(SkippedCard { id, name, error }),
+            }
+        }
 
-        Ok(json.data)
+        Ok((cards, skipped))
     }
 
+    /// Fetches the card named exactly `name`.
+    ///
+    /// If the API returns several entries for the name, only the first one is
+    /// kept; use [`Client::get_all_by_name`] to get all of them.
     pub async fn get_by_name(&self, name: &str) -> Result<Card, Error> {
+        let mut cards = self.get_all_by_name(name).await?;
+
+        Ok(cards.swap_remove(0))
+    }
+
+    /// Fetches the card with the given ID in Konami's official database.
+    pub async fn get_by_konami_id(&self, konami_id: u32) -> Result<Card, Error> {
+        let request = RequestBuilder::new().with_konami_id(konami_id).build();
+
+        self.get(request)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound)
+    }
+
+    /// Fetches every entry named exactly `name`, e.g. the separate entries of
+    /// a card reprinted under different IDs.
+    ///
+    /// The result is never empty: a name without matches is
+    /// [`Error::NotFound`].
+    pub async fn get_all_by_name(&self, name: &str) -> Result<Vec<Card>, Error> {
+        if self.offline.is_some() {
+            let request = RequestBuilder::new().with_name(name).build();
+            return self.get(request).await;
+        }
+
+        let url = format!(
+            "{}/cardinfo.php?name={}",
+            self.base_url,
+            urlencoding::encode(name),
+        );
+        if let Some(cards) = self.cached(&url) {
+            return Ok(cards);
+        }
+
+        let response = self.send(&url).await?;
+
+        let status = response.status().as_u16();
+        let json: ApiResponse = parse_json(response).await?;
+        let cards = json.into_cards(status)?;
+        if cards.is_empty() {
+            return Err(Error::NotFound);
+        }
+        self.store(url, &cards);
+
+        Ok(cards)
+    }
+
+    /// Lists every card set known to the API.
+    pub async fn get_card_sets(&self) -> Result<Vec<SetInfo>, Error> {
+        let response = self
+            .send(&format!("{}/cardsets.php", self.base_url))
+            .await?;
+
+        // unlike `cardinfo.php`, this endpoint returns a bare array
+        parse_json(response).await
+    }
+
+    /// Lists the sets released in the TCG strictly after `date`, in the
+    /// order the API sends them.
+    ///
+    /// The API can't filter sets by date, so every set is fetched and the
+    /// filtering is done here; sets without a TCG release date are left out.
+    pub async fn get_sets_released_after(&self, date: NaiveDate) -> Result<Vec<SetInfo>, Error> {
+        let mut sets = self.get_card_sets().await?;
+        sets.retain(|s| s.release_date().is_some_and(|released| released > date));
+
+        Ok(sets)
+    }
+
+    /// Finds the printing of `card` in the earliest released set.
+    ///
+    /// A card's printings carry no release date, so they are matched by set
+    /// name against [`Client::get_card_sets`] and compared by TCG release
+    /// date. Returns `None` when the card has no printing with a known date.
+    pub async fn first_printing(&self, card: &Card) -> Result<Option<CardSet>, Error> {
+        let printings = &card.info().sets;
+        if printings.is_empty() {
+            return Ok(None);
+        }
+
+        let sets = self.get_card_sets().await?;
+        let release_date = |printing: &CardSet| {
+            sets.iter()
+                .find(|s| s.name == printing.name)?
+                .release_date()
+        };
+
+        Ok(printings
+            .iter()
+            .filter_map(|p| Some((release_date(p)?, p)))
+            .min_by_key(|(date, _)| *date)
+            .map(|(_, printing)| printing.clone()))
+    }
+
+    /// Fetches the set-specific information (rarity, price...) of the card
+    /// printed as `set_code`, e.g. `"SDY-001"`.
+    ///
+    /// Unknown set codes are reported as [`Error::NotFound`].
+    pub async fn get_set_card_info(&self, set_code: &str) -> Result<SetCardInfo, Error> {
         let response = self
-            .client
-            .get(format!(
-                "https://db.ygoprodeck.com/api/v7/cardinfo.php?name={}",
-                urlencoding::encode(name),
+            .send(&format!(
+                "{}/cardsetsinfo.php?setcode={}",
+                self.base_url,
+                urlencoding::encode(set_code)
             ))
-            .send()
             .await
-            .map_err(|e| Error::Network(e))?;
+            .map_err(|e| match e {
+                Error::Api { status: 400, .. } => Error::NotFound,
+                e => e,
+            })?;
 
-        if response.status() == 400 {
-            return Err(Error::NotFound);
+        match parse_json(response).await? {
+            SetCardInfoResponse::Found(info) => Ok(info),
+            SetCardInfoResponse::Error(_) => Err(Error::NotFound),
         }
+    }
 
-        let json = response.json::<ApiResponse>().await.map_err(|e| {
-            dbg!(&e);
-            Error::Deserialization
-        })?;
+    /// Lists the names of every archetype known to the API.
+    pub async fn get_archetypes(&self) -> Result<Vec<String>, Error> {
+        let response = self
+            .send(&format!("{}/archetypes.php", self.base_url))
+            .await?;
+        let archetypes: Vec<ArchetypeResponse> = parse_json(response).await?;
 
-        match json.data.into_iter().next() {
-            Some(c) => Ok(c),
-            None => Err(Error::NotFound),
-        }
+        Ok(archetypes.into_iter().map(|a| a.archetype_name).collect())
     }
-}
 
-#[derive(Deserialize)]
-struct ApiResponse {
-    pub data: Vec<Card>,
-}
+    /// Fetches the version of the card database, which changes whenever the
+    /// API data is updated, e.g. to know when a local copy is stale.
+    pub async fn check_db_version(&self) -> Result<DbVersion, Error> {
+        let response = self
+            .send(&format!("{}/checkDBVer.php", self.base_url))
+            .await?;
+        let versions: Vec<DbVersion> = parse_json(response).await?;
 
-#[derive(Debug)]
-pub enum Error {
-    Network(reqwest::Error),
-    NotFound,
-    Serialization,
-    Deserialization,
-}
+        // the version is sent as an array holding a single element
+        versions.into_iter().next().ok_or_else(|| {
+            Error::Deserialization(serde::de::Error::invalid_length(0, &"one database version"))
+        })
+    }
 
-impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Error::Network(error) => write!(f, "Network error: {error}"),
-            Error::NotFound => write!(f, "Card not found"),
-            Error::Serialization => write!(f, "Failed to serialize request"),
-            Error::Deserialization => write!(f, "Failed to deserialize response payload"),
+    /// Fetches several cards by their exact names in a single request.
+    ///
+    /// Names that do not resolve are left out, so the result can be shorter
+    /// than `names`; it is [`Error::NotFound`] only when none of them match.
+    /// Cards are returned in the order the API sends them, which is not
+    /// necessarily the order of `names`.
+    pub async fn get_by_names(&self, names: &[&str]) -> Result<Vec<Card>, Error> {
+        // an empty request would return the whole database
+        if names.is_empty() {
+            return Ok(Vec::new());
         }
+
+        let request = names
+            .iter()
+            .fold(RequestBuilder::new(), |builder, name| {
+                builder.with_name(name)
+            })
+            .build();
+
+        self.get(request).await
     }
-}
 
-impl std::error::Error for Error {}
+    /// Runs several requests concurrently and returns the union of their
+    /// results, for queries the API can't express as one request (e.g.
+    /// Dragons or Spellcasters).
+    ///
+    /// Cards matched by more than one request are returned once, in the order
+    /// they were first seen, going through the requests in order. It is
+    /// [`Error::NotFound`] only when none of the requests match; any other
+    /// error fails the whole call.
+    pub async fn get_any(&self, requests: &[Request<'_>]) -> Result<Vec<Card>, Error> {
+        let results = future::join_all(requests.iter().map(|request| async move {
+            let mut cards = self.fetch(request).await?;
+            cards.retain(|c| request.post_filter(c));
+            Ok::<_, Error>(cards)
+        }))
+        .await;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{
-        card::{Attribute, CardId, LinkMarker, MonsterRace, MonsterType, SpellRace, TrapRace},
-        request::{CardType, RequestBuilder},
-    };
+        let mut cards: Vec<Card> = Vec::new();
+        let mut not_found = 0;
+        for result in results {
+            match result {
+                Ok(found) => {
+                    for card in found {
+                        if !cards.iter().any(|c| c.id() == card.id()) {
+                            cards.push(card);
+                        }
+                    }
+                }
+                Err(Error::NotFound) => not_found += 1,
+                Err(e) => return Err(e),
+            }
+        }
 
-    #[tokio::test]
-    async fn get() {
-        let client = Client::new();
-        let request = RequestBuilder::new()
-            .with_type(CardType::LinkMonster)
-            .with_attribute(Attribute::Wind)
-            .with_link_marker(LinkMarker::Top)
-            .with_link_marker(LinkMarker::Bottom)
-            .with_link_marker(LinkMarker::BottomRight)
-            .with_link_marker(LinkMarker::BottomLeft)
-            .build();
-        let result = client.get(request).await;
-        assert!(result.is_ok());
-        let cards = result.unwrap();
-        assert_eq!(cards.len(), 2);
+        if not_found > 0 && not_found == requests.len() {
+            return Err(Error::NotFound);
+        }
+
+        Ok(cards)
     }
 
-    #[tokio::test]
-    async fn list_all_cards() {
-        let client = Client::new();
-        let result = client.get(Request::default()).await;
-        assert!(result.is_ok());
-        let cards = result.unwrap();
-        assert!(cards.len() > 200);
+    /// Runs `request` once for each set of `sets` and returns the cards
+    /// printed in any of them, as [`Client::get_any`] does.
+    ///
+    /// The API only filters on one set at a time, so this makes one request
+    /// per set, replacing any set `request` was built with.
+    pub async fn get_from_sets(
+        &self,
+        request: Request<'_>,
+        sets: &[&str],
+    ) -> Result<Vec<Card>, Error> {
+        if sets.is_empty() {
+            return Err(Error::InvalidRequest("no card set given".to_string()));
+        }
+
+        let requests: Vec<_> = sets
+            .iter()
+            .map(|set| {
+                let mut request = request.clone();
+                request.set_cardset(set);
+                request
+            })
+            .collect();
+
+        self.get_any(&requests).await
     }
 
-    #[tokio::test]
-    async fn get_normal_monsters_with_1800_atk() {
-        let client = Client::new();
-        let request = RequestBuilder::new()
-            .with_atk(1800)
-            .with_attribute(Attribute::Dark)
-            .with_type(CardType::NormalMonster)
+    /// Looks up the cards of a deck, fetching all of its distinct IDs in a
+    /// single request.
+    ///
+    /// Every copy listed in `ydk` gets its own entry in the resolved deck.
+    /// IDs of alternate artworks resolve to their card, and IDs that match no
+    /// card are reported in [`ResolvedDeck::missing`] rather than as an error.
+    pub async fn resolve_deck(&self, ydk: &Ydk) -> Result<ResolvedDeck, Error> {
+        let ids = ydk.unique_ids();
+        if ids.is_empty() {
+            return Ok(ResolvedDeck::default());
+        }
+
+        let request = ids
+            .iter()
+            .fold(RequestBuilder::new(), |builder, id| builder.with_id(*id))
             .build();
-        let result = client.get(request).await;
-        assert!(result.is_ok());
-        let cards = result.unwrap();
-        assert!(cards.iter().any(|c| matches!(c, Card::Normal(_))));
+        let cards = match self.get(request).await {
+            Ok(cards) => cards,
+            Err(Error::NotFound) => Vec::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut missing = Vec::new();
+        let mut resolve = |ids: &[CardId]| -> Vec<Card> {
+            ids.iter()
+                .filter_map(|id| {
+                    let card = cards
+                        .iter()
+                        .find(|c| c.id() == *id || c.info().images.iter().any(|i| i.id == id.0));
+                    if card.is_none() {
+                        missing.push(*id);
+                    }
+                    card.cloned()
+                })
+                .collect()
+        };
+
+        let main = resolve(&ydk.main);
+        let extra = resolve(&ydk.extra);
+        let side = resolve(&ydk.side);
+
+        Ok(ResolvedDeck {
+            main,
+            extra,
+            side,
+            missing,
+        })
     }
 
-    #[tokio::test]
-    async fn get_normal_monster() {
-        let client = Client::new();
-        let result = client.get_by_name("Trent").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    /// Searches for cards whose name contains `fragment`, best matches first.
+    ///
+    /// Results are ranked in this order: the exact name, names starting with
+    /// `fragment`, names where it starts a word, then every other name
+    /// containing it, all ignoring case. Cards of the same rank are sorted
+    /// alphabetically.
+    pub async fn search(&self, fragment: &str) -> Result<Vec<Card>, Error> {
+        let request = RequestBuilder::new().with_fname(fragment).build();
+        let mut cards = self.get(request).await?;
 
-        match card {
-            Card::Normal(m) => {
-                assert_eq!(m.info.id, CardId(78780140));
-                assert_eq!(m.info.name, "Trent");
-                assert_eq!(
-                    m.info.desc,
-                    "A guardian of the woods, this massive tree is believed to be immortal."
-                );
-                assert_eq!(m.race, MonsterRace::Plant);
-                assert_eq!(m.attribute, Attribute::Earth);
-                assert_eq!(m.level, 5);
-                assert_eq!(m.atk, 1500);
-                assert_eq!(m.def, 1800);
-                assert_eq!(m.card_type, MonsterType::NormalMonster);
-                assert_eq!(m.info.human_readable_card_type, "Normal Monster");
-                assert_eq!(
-                    m.info.ygoprodeck_url,
-                    "https://ygoprodeck.com/card/trent-6617"
-                );
+        let fragment = fragment.to_lowercase();
+        let rank = |card: &Card| {
+            let name = card.name().to_lowercase();
+            if name == fragment {
+                0
+            } else if name.starts_with(&fragment) {
+                1
+            } else if name
+                .match_indices(&fragment)
+                .any(|(i, _)| name[..i].ends_with(|c: char| !c.is_alphanumeric()))
+            {
+                2
+            } else {
+                3
             }
-            _ => panic!("Unexpected variant"),
-        }
+        };
+        cards.sort_by_cached_key(|c| (rank(c), c.name().to_lowercase()));
+
+        Ok(cards)
     }
 
-    #[tokio::test]
-    async fn get_link_monster() {
-        let client = Client::new();
-        let result = client.get_by_name("Apollousa, Bow of the Goddess").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    /// Fetches the cards whose name starts with `prefix`, ignoring case, in
+    /// the order the API sends them.
+    ///
+    /// The API only offers substring search, so the cards are fetched with
+    /// `fname` and those where `prefix` appears later in the name are
+    /// filtered out here. The result can thus be empty even when the request
+    /// succeeded.
+    pub async fn search_prefix(&self, prefix: &str) -> Result<Vec<Card>, Error> {
+        let request = RequestBuilder::new().with_fname(prefix).build();
+        let mut cards = self.get(request).await?;
 
-        match card {
-            Card::Link(m) => {
-                assert_eq!(m.info.id, CardId(4280258));
-                assert_eq!(m.info.name, "Apollousa, Bow of the Goddess");
-                assert_eq!(
-                    m.info.desc,
-                    "2+ monsters with different names, except Tokens\r\nYou can only control 1 \"Apollousa, Bow of the Goddess\". The original ATK of this card becomes 800 x the number of Link Materials used for its Link Summon. Once per Chain, when your opponent activates a monster effect (Quick Effect): You can make this card lose exactly 800 ATK, and if you do, negate the activation."
-                );
-                assert_eq!(m.race, MonsterRace::Fairy);
-                assert_eq!(m.attribute, Attribute::Wind);
-                assert_eq!(m.atk, -1); // ? atk
-                assert_eq!(m.linkval, 4);
-                assert_eq!(m.card_type, MonsterType::LinkMonster);
-                assert_eq!(
-                    m.link_markers,
-                    vec![
-                        LinkMarker::Top,
-                        LinkMarker::BottomLeft,
-                        LinkMarker::Bottom,
-                        LinkMarker::BottomRight
-                    ]
-                );
-                assert_eq!(m.info.human_readable_card_type, "Link Effect Monster");
-                assert_eq!(
-                    m.info.ygoprodeck_url,
-                    "https://ygoprodeck.com/card/apollousa-bow-of-the-goddess-10242"
-                );
+        let prefix = prefix.to_lowercase();
+        cards.retain(|card| card.name().to_lowercase().starts_with(&prefix));
+
+        Ok(cards)
+    }
+
+    /// Resolves a noisy card name, such as the output of OCR, to the card
+    /// whose name is the closest match.
+    ///
+    /// Candidates are fetched with `fname` using the longest words of
+    /// `ocr_text` (the ones least likely to be mangled), then the candidates
+    /// of every word are ranked together by their edit-distance similarity to
+    /// the whole input, which tolerates missing, extra and misread
+    /// characters. The best candidate is returned only if its score, between
+    /// `0.0` and `1.0`, is greater than `threshold`.
+    pub async fn resolve_fuzzy_best(
+        &self,
+        ocr_text: &str,
+        threshold: f64,
+    ) -> Result<Option<Card>, Error> {
+        let mut words: Vec<&str> = ocr_text
+            .split_whitespace()
+            .filter(|w| w.chars().count() >= 3)
+            .collect();
+        words.sort_by_key(|w| std::cmp::Reverse(w.chars().count()));
+
+        // a mangled word can still hit unrelated cards, so every word is tried
+        let mut best: Option<(f64, Card)> = None;
+        for word in words.into_iter().take(FUZZY_MAX_QUERIES) {
+            let request = RequestBuilder::new().with_fname(word).build();
+            let candidates = match self.get(request).await {
+                Ok(cards) => cards,
+                Err(Error::NotFound) => continue,
+                Err(e) => return Err(e),
+            };
+
+            for card in candidates {
+                let score = similarity(card.name(), ocr_text);
+                if best.as_ref().is_none_or(|(best, _)| score > *best) {
+                    best = Some((score, card));
+                }
             }
-            _ => panic!("Unexpected variant"),
         }
+
+        Ok(best
+            .filter(|(score, _)| *score > threshold)
+            .map(|(_, card)| card))
     }
 
-    #[tokio::test]
-    async fn get_effect_monster() {
-        let client = Client::new();
-        let result = client.get_by_name("Man-eater Bug").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    /// Resolves a `https://ygoprodeck.com/card/<slug>` link back to its card.
+    ///
+    /// The slug is the card's name with its punctuation dropped, so
+    /// candidates are fetched with `fname` using its longest words and the
+    /// card whose [`ygoprodeck_url`](crate::card::CardInfo::ygoprodeck_url)
+    /// has the same slug is returned. A link that isn't a YGOProDeck card
+    /// page is an [`Error::InvalidRequest`].
+    pub async fn get_by_url(&self, url: &str) -> Result<Card, Error> {
+        let slug = card_slug(url)
+            .ok_or_else(|| Error::InvalidRequest(format!("not a YGOProDeck card URL: `{url}`")))?;
 
-        match card {
-            Card::Effect(m) => {
-                assert_eq!(m.info.id, CardId(54652250));
-                assert_eq!(m.info.name, "Man-Eater Bug");
-                assert_eq!(
-                    m.info.desc,
-                    "FLIP: Target 1 monster on the field; destroy it."
-                );
-                assert_eq!(m.race, MonsterRace::Insect);
-                assert_eq!(m.attribute, Attribute::Earth);
-                assert_eq!(m.level, 2);
-                assert_eq!(m.atk, 450);
-                assert_eq!(m.def, 600);
-                assert_eq!(m.card_type, MonsterType::FlipEffectMonster);
-                assert_eq!(m.info.human_readable_card_type, "Flip Effect Monster");
-                assert_eq!(
-                    m.info.ygoprodeck_url,
-                    "https://ygoprodeck.com/card/man-eater-bug-4659"
-                );
+        let mut words: Vec<&str> = slug
+            .split('-')
+            .filter(|w| !w.is_empty() && !w.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        words.sort_by_key(|w| std::cmp::Reverse(w.chars().count()));
+
+        for word in words.into_iter().take(FUZZY_MAX_QUERIES) {
+            let request = RequestBuilder::new().with_fname(word).build();
+            let candidates = match self.get(request).await {
+                Ok(cards) => cards,
+                Err(Error::NotFound) => continue,
+                Err(e) => return Err(e),
+            };
+
+            if let Some(card) = candidates
+                .into_iter()
+                .find(|c| card_slug(&c.info().ygoprodeck_url).as_ref() == Some(&slug))
+            {
+                return Ok(card);
             }
-            _ => panic!("Unexpected monster variant"),
         }
+
+        Err(Error::NotFound)
     }
 
-    #[tokio::test]
-    async fn get_normal_spell() {
-        let client = Client::new();
-        let result = client.get_by_name("Pot of Greed").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    /// Lazily pages through the cards of `archetype`, fetching the next page
+    /// only once the previous one has been consumed.
+    ///
+    /// An archetype without cards yields a single [`Error::NotFound`].
+    pub fn archetype_stream<'s>(
+        &'s self,
+        archetype: &'s str,
+    ) -> impl Stream<Item = Result<Card, Error>> + 's {
+        self.paginate(RequestBuilder::new().with_archetype(archetype).build())
+    }
 
-        match card {
-            Card::Spell(s) => {
-                assert_eq!(s.info.id, CardId(55144522));
-                assert_eq!(s.info.name, "Pot of Greed");
-                assert_eq!(s.info.desc, "Draw 2 cards.");
-                assert_eq!(s.race, SpellRace::Normal);
-                assert_eq!(s.info.human_readable_card_type, "Normal Spell");
-                assert_eq!(
-                    s.info.ygoprodeck_url,
-                    "https://ygoprodeck.com/card/pot-of-greed-4698"
-                );
+    /// Downloads the whole card database, `page_size` cards per request
+    /// rather than in one huge response.
+    ///
+    /// `progress` is called after each page with the number of cards fetched
+    /// so far. Paging stops at the first page with fewer than `page_size`
+    /// cards. A `page_size` of 0 is an [`Error::InvalidRequest`].
+    pub async fn get_all(
+        &self,
+        page_size: u16,
+        mut progress: impl FnMut(usize),
+    ) -> Result<Vec<Card>, Error> {
+        if page_size == 0 {
+            return Err(Error::InvalidRequest("page size must not be 0".to_string()));
+        }
+
+        let page_size = u32::from(page_size);
+        let mut request = Request::all_cards();
+        let mut cards = Vec::new();
+
+        loop {
+            request.set_page(page_size, cards.len() as u32);
+
+            let page = match self.fetch(&request).await {
+                Ok(page) => page,
+                // the previous page was exactly the last one
+                Err(Error::NotFound) if !cards.is_empty() => break,
+                Err(e) => return Err(e),
+            };
+            let last_page = page.len() < page_size as usize;
+            cards.extend(page);
+            progress(cards.len());
+
+            if last_page {
+                break;
             }
-            _ => panic!("Unexpected monster variant"),
         }
+
+        Ok(cards)
     }
 
-    #[tokio::test]
-    async fn get_normal_trap() {
-        let client = Client::new();
-        let result = client.get_by_name("Reckless Greed").await;
+    /// Lazily pages through the cards matching `request`, a hundred at a
+    /// time, so that large results such as the whole database are never held
+    /// in memory at once.
+    ///
+    /// Any `num`/`offset` set on the request are replaced by the paging. A
+    /// request without matches yields a single [`Error::NotFound`].
+    pub fn stream<'s>(
+        &'s self,
+        request: Request<'s>,
+    ) -> impl Stream<Item = Result<Card, Error>> + 's {
+        self.paginate(request)
+    }
+
+    /// Yields the cards matching `request`, fetching them [`PAGE_SIZE`] at a
+    /// time with `num`/`offset`.
+    fn paginate<'s>(
+        &'s self,
+        request: Request<'s>,
+    ) -> impl Stream<Item = Result<Card, Error>> + 's {
+        stream::unfold(Some((request, 0)), move |state| async move {
+            let (mut request, offset) = state?;
+            request.set_page(PAGE_SIZE, offset);
+
+            match self.fetch(&request).await {
+                Ok(cards) => {
+                    let last_page = cards.len() < PAGE_SIZE as usize;
+                    let page: Vec<_> = cards
+                        .into_iter()
+                        .filter(|c| request.post_filter(c))
+                        .map(Ok)
+                        .collect();
+                    let next = (!last_page).then(|| (request, offset + PAGE_SIZE));
+
+                    Some((page, next))
+                }
+                // the previous page was exactly the last one
+                Err(Error::NotFound) if offset > 0 => None,
+                Err(e) => Some((vec![Err(e)], None)),
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Fetches the cards matching the filters `request` sends to the API,
+    /// without applying its client-side filters.
+    async fn fetch(&self, request: &Request<'_>) -> Result<Vec<Card>, Error> {
+        request.validate()?;
+
+        if let Some(cards) = &self.offline {
+            let cards = request.page(cards.iter().filter(|c| request.matches(c)));
+
+            if cards.is_empty() {
+                return Err(Error::NotFound);
+            }
+
+            return Ok(cards);
+        }
+
+        let url = format!("{}/cardinfo.php?{}", self.base_url, request.to_url_params());
+        if let Some(cards) = self.cached(&url) {
+            return Ok(cards);
+        }
+
+        let response = self.send_with_timeout(&url, request.timeout()).await?;
+
+        let status = response.status().as_u16();
+        let json: ApiResponse = parse_json(response).await?;
+        let cards = json.into_cards(status)?;
+        self.store(url, &cards);
+
+        Ok(cards)
+    }
+
+    /// Downloads the raw bytes at `url`, e.g. a card image.
+    ///
+    /// Any failure, including a non-success status, is an [`Error::Network`].
+    pub(crate) async fn download(&self, url: &str) -> Result<Vec<u8>, Error> {
+        let response = self
+            .get_request(url, None)
+            .await
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(Error::Network)?;
+        let bytes = response.bytes().await.map_err(Error::Network)?;
+
+        Ok(bytes.to_vec())
+    }
+
+    fn cached(&self, url: &str) -> Option<Vec<Card>> {
+        let cache = self.cache.as_ref()?;
+
+        cache.lock().expect("card cache poisoned").get(url)
+    }
+
+    fn store(&self, url: String, cards: &[Card]) {
+        if let Some(cache) = &self.cache {
+            cache
+                .lock()
+                .expect("card cache poisoned")
+                .insert(url, cards.to_vec());
+        }
+    }
+
+    /// Sends a `GET` request to `url`, retrying transient failures as
+    /// configured by [`Client::with_retries`].
+    async fn send(&self, url: &str) -> Result<reqwest::Response, Error> {
+        self.send_with_timeout(url, None).await
+    }
+
+    /// A `GET` request to `url`, bounded by `timeout` or else by the client's
+    /// own timeout. Waits for the rate limiter first, if there is one.
+    async fn get_request(&self, url: &str, timeout: Option<Duration>) -> reqwest::RequestBuilder {
+        if let Some(limiter) = &self.rate_limit {
+            limiter.acquire().await;
+        }
+
+        let builder = self.client.get(url);
+
+        match timeout.or(self.timeout) {
+            Some(timeout) => builder.timeout(timeout),
+            None => builder,
+        }
+    }
+
+    /// Like [`Client::send`], with `timeout` replacing the client's one.
+    async fn send_with_timeout(
+        &self,
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<reqwest::Response, Error> {
+        let mut attempt = 0;
+
+        loop {
+            let result = match self.get_request(url, timeout).await.send().await {
+                Ok(response) => check_status(response).await,
+                Err(e) => Err(Error::Network(e)),
+            };
+
+            match result {
+                Err(e) if attempt < self.retries && e.is_transient() => {
+                    let mut backoff = RETRY_BASE_DELAY.saturating_mul(2u32.saturating_pow(attempt));
+                    if let Error::RateLimited {
+                        retry_after: Some(retry_after),
+                    } = e
+                    {
+                        backoff = backoff.max(retry_after);
+                    }
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Number of cards fetched per request when paging through results.
+const PAGE_SIZE: u32 = 100;
+
+/// Delay before the first retry of a failed request.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Upper bound on the `fname` lookups [`Client::resolve_fuzzy_best`] makes for
+/// a single input.
+const FUZZY_MAX_QUERIES: usize = 3;
+
+/// Turns a non-success response into an error, keeping the message the API
+/// sent in its `{"error": "..."}` body.
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, Error> {
+    let status = response.status();
+
+    if let Some(error) = rate_limited(status, response.headers()) {
+        return Err(error);
+    }
+
+    if let Some(error) = non_json_error(status, response.headers()) {
+        return Err(error);
+    }
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await.map_err(Error::Network)?;
+
+    Err(error_from_body(status, body))
+}
+
+/// Strips the trailing slashes of a base URL, so that endpoints can be
+/// appended as `{base}/cardinfo.php`.
+pub(crate) fn normalize_base_url(mut base_url: String) -> String {
+    while base_url.ends_with('/') {
+        base_url.pop();
+    }
+
+    base_url
+}
+
+/// Detects a `429 Too Many Requests` response, whatever its body.
+///
+/// Only the delay-seconds form of `Retry-After` is understood; an HTTP date
+/// leaves `retry_after` unset.
+pub(crate) fn rate_limited(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> Option<Error> {
+    if status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+
+    let retry_after = headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+        .map(Duration::from_secs);
+
+    Some(Error::RateLimited { retry_after })
+}
+
+/// Rejects responses whose `Content-Type` isn't JSON.
+///
+/// Outages are announced with an HTML maintenance page, which would otherwise
+/// surface as a baffling deserialization error.
+pub(crate) fn non_json_error(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> Option<Error> {
+    let non_json = headers
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| !v.contains("json"));
+
+    non_json.then(|| Error::Api {
+        status: status.as_u16(),
+        message: "non-JSON response".to_string(),
+    })
+}
+
+/// Builds the error for a non-success response from its body.
+pub(crate) fn error_from_body(status: reqwest::StatusCode, body: String) -> Error {
+    let message = match serde_json::from_str::<ApiErrorResponse>(&body) {
+        Ok(e) => e.error,
+        Err(_) => body,
+    };
+
+    api_error(status.as_u16(), message)
+}
+
+/// Classifies an error message sent by the API.
+fn api_error(status: u16, message: String) -> Error {
+    if message.starts_with("No card matching") {
+        return Error::NotFound;
+    }
+
+    Error::Api { status, message }
+}
+
+/// Extracts the lowercased slug of a `https://ygoprodeck.com/card/<slug>` link.
+fn card_slug(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+
+    if !matches!(url.host_str()?, "ygoprodeck.com" | "www.ygoprodeck.com") {
+        return None;
+    }
+
+    let mut segments = url.path_segments()?.filter(|s| !s.is_empty());
+
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("card"), Some(slug), None) => Some(slug.to_lowercase()),
+        _ => None,
+    }
+}
+
+/// Scores how similar two card names are, from `0.0` to `1.0`, ignoring case
+/// and punctuation.
+fn similarity(a: &str, b: &str) -> f64 {
+    let normalize = |s: &str| -> Vec<char> {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+    let len = a.len().max(b.len());
+
+    if len == 0 {
+        return 1.0;
+    }
+
+    1.0 - levenshtein(&a, &b) as f64 / len as f64
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Reads a response body and deserializes it as JSON.
+///
+/// Failing to read the body, e.g. on a timeout, is an [`Error::Network`];
+/// a body that doesn't match `T` is an [`Error::Deserialization`].
+#[cfg(not(feature = "stream-parse"))]
+async fn parse_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, Error> {
+    let bytes = response.bytes().await.map_err(Error::Network)?;
+
+    serde_json::from_slice(&bytes).map_err(Error::Deserialization)
+}
+
+/// Reads a response body and deserializes it as JSON, as it arrives.
+///
+/// The body is parsed on a blocking thread from a reader over its byte
+/// stream, so it is never held in memory as a whole, only the values it
+/// decodes to. Errors are reported as by the default, buffering version,
+/// plus [`Error::Cancelled`] if the runtime shuts down meanwhile. A panic of
+/// the parser is propagated as is.
+///
+/// This trades speed for memory: reading through the bridge is slower than
+/// parsing a buffered slice, by about 15-30% on a full database dump, for a
+/// peak RSS lower by about the size of the body.
+#[cfg(feature = "stream-parse")]
+async fn parse_json<T: DeserializeOwned + Send + 'static>(
+    response: reqwest::Response,
+) -> Result<T, Error> {
+    use std::io::{self, BufReader};
+
+    use tokio_util::io::{StreamReader, SyncIoBridge};
+
+    let body = StreamReader::new(
+        response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(io::Error::other)),
+    );
+    let reader = SyncIoBridge::new(body);
+
+    let parsed =
+        tokio::task::spawn_blocking(move || serde_json::from_reader(BufReader::new(reader))).await;
+
+    match parsed {
+        Ok(parsed) => parsed.map_err(read_error),
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(_) => Err(Error::Cancelled),
+    }
+}
+
+/// Recovers the network error that interrupted a streamed body, if that is
+/// why `error` happened.
+#[cfg(feature = "stream-parse")]
+pub(crate) fn read_error(error: serde_json::Error) -> Error {
+    use std::io;
+
+    if !error.is_io() {
+        return Error::Deserialization(error);
+    }
+
+    let error = io::Error::from(error);
+    let kind = error.kind();
+    let error = match error
+        .into_inner()
+        .map(|inner| inner.downcast::<reqwest::Error>())
+    {
+        Some(Ok(network)) => return Error::Network(*network),
+        Some(Err(inner)) => io::Error::new(kind, inner),
+        None => io::Error::from(kind),
+    };
+
+    Error::Deserialization(serde_json::Error::io(error))
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The version of the API's card database.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DbVersion {
+    #[serde(rename = "database_version")]
+    pub version: String,
+    /// When the database was last updated, e.g. `"2025-11-03 10:12:23"`.
+    pub last_update: String,
+}
+
+/// A card the API sent that couldn't be read, see [`Client::get_lenient`].
+#[derive(Debug)]
+pub struct SkippedCard {
+    /// The card's ID, if the entry had a readable one.
+    pub id: Option<CardId>,
+    /// The card's name, if the entry had a readable one.
+    pub name: Option<String>,
+    /// Why the entry couldn't be read as a [`Card`].
+    pub error: serde_json::Error,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ApiResponse<T = Card> {
+    // some error responses carry an `error` message instead of `data`
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl<T> ApiResponse<T> {
+    pub(crate) fn into_cards(self, status: u16) -> Result<Vec<T>, Error> {
+        match self.error {
+            Some(message) => Err(api_error(status, message)),
+            None => Ok(self.data),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ArchetypeResponse {
+    archetype_name: String,
+}
+
+#[derive(Deserialize)]
+struct ApiErrorResponse {
+    error: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SetCardInfoResponse {
+    Found(SetCardInfo),
+    Error(#[allow(dead_code)] ApiErrorResponse),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Network(reqwest::Error),
+    NotFound,
+    /// The cached card database could not be read.
+    Io(std::io::Error),
+    /// The request was not sent because one of its filters is malformed.
+    InvalidRequest(String),
+    /// The API rejected the request, e.g. because of an invalid parameter.
+    Api {
+        status: u16,
+        message: String,
+    },
+    /// The API is rate limiting this client. `retry_after` is how long it
+    /// asked to wait before sending another request, if it said.
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
+    Serialization,
+    /// The response body doesn't have the expected shape.
+    Deserialization(serde_json::Error),
+    /// The runtime shut down while the response was being parsed, which
+    /// only happens with the `stream-parse` feature.
+    Cancelled,
+}
+
+impl Error {
+    /// Whether the request may succeed if it is sent again.
+    fn is_transient(&self) -> bool {
+        match self {
+            Error::Network(_) | Error::RateLimited { .. } => true,
+            Error::Api { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Network(error) => write!(f, "Network error: {error}"),
+            Error::NotFound => write!(f, "Card not found"),
+            Error::Io(error) => write!(f, "Failed to read cached database: {error}"),
+            Error::InvalidRequest(reason) => write!(f, "Invalid request: {reason}"),
+            Error::Api { status, message } => write!(f, "API error ({status}): {message}"),
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "Rate limited, retry after {}s", retry_after.as_secs()),
+            Error::RateLimited { retry_after: None } => write!(f, "Rate limited"),
+            Error::Serialization => write!(f, "Failed to serialize request"),
+            Error::Deserialization(error) => {
+                write!(f, "Failed to deserialize response payload: {error}")
+            }
+            Error::Cancelled => write!(f, "Cancelled while parsing the response"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Network(error) => Some(error),
+            Error::Io(error) => Some(error),
+            Error::Deserialization(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card::{
+            Attribute, CardId, CardImage, Format, LinkMarker, MonsterRace, MonsterType, SpellRace,
+            StatValue, TrapRace,
+        },
+        request::{CardType, DateRegion, Language, RequestBuilder, SortOrder},
+        testing::{NOT_FOUND, card_json, mock_api, not_found},
+    };
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{header, method, path, query_param, query_param_is_missing},
+    };
+
+    fn normal_monster_json(id: u64, name: &str) -> serde_json::Value {
+        card_json(
+            "normal",
+            serde_json::json!({
+                "id": id,
+                "name": name,
+                "type": "Normal Monster",
+                "humanReadableCardType": "Normal Monster",
+                "race": "Dragon",
+                "attribute": "LIGHT",
+                "level": 8,
+                "atk": 3000,
+                "def": 2500
+            }),
+        )
+    }
+
+    fn trent_json() -> serde_json::Value {
+        serde_json::json!({
+            "data": [{
+                "id": 78780140,
+                "name": "Trent",
+                "type": "Normal Monster",
+                "humanReadableCardType": "Normal Monster",
+                "frameType": "normal",
+                "desc": "A guardian of the woods, this massive tree is believed to be immortal.",
+                "race": "Plant",
+                "attribute": "EARTH",
+                "level": 5,
+                "atk": 1500,
+                "def": 1800,
+                "ygoprodeck_url": "https://ygoprodeck.com/card/trent-6617",
+                "card_images": [{
+                    "id": 78780140,
+                    "image_url": "https://images.ygoprodeck.com/images/cards/78780140.jpg",
+                    "image_url_small": "https://images.ygoprodeck.com/images/cards_small/78780140.jpg",
+                    "image_url_cropped": "https://images.ygoprodeck.com/images/cards_cropped/78780140.jpg"
+                }]
+            }]
+        })
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new()
+            .with_type(CardType::LinkMonster)
+            .with_attribute(Attribute::Wind)
+            .with_link_marker(LinkMarker::Top)
+            .with_link_marker(LinkMarker::Bottom)
+            .with_link_marker(LinkMarker::BottomRight)
+            .with_link_marker(LinkMarker::BottomLeft)
+            .build();
+        let result = client.get(request).await;
+        assert!(result.is_ok());
+        let cards = result.unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].name(), "Apollousa, Bow of the Goddess");
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn list_all_cards() {
+        let client = Client::new();
+        let result = client.get(Request::default()).await;
+        assert!(result.is_ok());
+        let cards = result.unwrap();
+        assert!(cards.len() > 200);
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_staples() {
+        let client = Client::new();
+        let request = RequestBuilder::new().with_staple(true).build();
+        let staples = client.get(request).await.unwrap();
+        let all = client.get(Request::all_cards()).await.unwrap();
+        assert!(!staples.is_empty());
+        assert!(staples.len() < all.len());
+    }
+
+    #[tokio::test]
+    async fn get_normal_monsters_with_1800_atk() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new()
+            .with_atk(1800)
+            .with_attribute(Attribute::Dark)
+            .with_type(CardType::NormalMonster)
+            .build();
+        let result = client.get(request).await;
+        assert!(result.is_ok());
+        let cards = result.unwrap();
+        assert!(cards.iter().any(|c| matches!(c, Card::Normal(_))));
+    }
+
+    #[tokio::test]
+    async fn get_token() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Sheep Token").await;
+        assert!(result.is_ok());
+        let Card::Token(token) = result.unwrap() else {
+            panic!("expected a token");
+        };
+        assert_eq!(token.info.name, "Sheep Token");
+        assert_eq!(token.race, MonsterRace::Beast);
+        assert_eq!(token.attribute, Attribute::Earth);
+    }
+
+    #[tokio::test]
+    async fn get_skill() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Destiny Draw").await;
+        assert!(result.is_ok());
+        let Card::Skill(skill) = result.unwrap() else {
+            panic!("expected a skill");
+        };
+        assert_eq!(skill.info.name, "Destiny Draw");
+        assert!(!skill.info.desc.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_vanilla_monsters() {
+        let client = Client::new();
+        let request = RequestBuilder::new()
+            .with_level(4)
+            .with_attribute(Attribute::Light)
+            .with_has_effect(false)
+            .with_misc(true)
+            .build();
+        let result = client.get(request).await;
+        assert!(result.is_ok());
+        let cards = result.unwrap();
+        assert!(!cards.is_empty());
+        assert!(
+            cards
+                .iter()
+                .all(|c| c.info().misc.as_ref().is_some_and(|m| !m.has_effect))
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_in_french() {
+        let client = Client::new();
+        let english = client.get_by_name("Pot of Greed").await.unwrap();
+        let request = RequestBuilder::new()
+            .with_fname("Pot")
+            .with_language(Language::French)
+            .build();
+        let french = client.get(request).await.unwrap();
+        let french = french.iter().find(|c| c.id() == english.id()).unwrap();
+        assert_ne!(french.info().desc, english.info().desc);
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_goat_format() {
+        let client = Client::new();
+        let request = RequestBuilder::new()
+            .with_format(Format::Goat)
+            .with_misc(true)
+            .build();
+        let goat = client.get(request).await.unwrap();
+        let all = client.get(Request::all_cards()).await.unwrap();
+        assert!(!goat.is_empty());
+        assert!(goat.len() < all.len());
+        assert!(goat.iter().all(|c| {
+            c.info()
+                .misc
+                .as_ref()
+                .is_some_and(|m| m.formats.iter().any(|f| f == "GOAT"))
+        }));
+    }
+
+    #[tokio::test]
+    async fn inverted_range() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trent_json()))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new().with_atk_range(2000, 1500).build();
+        assert!(matches!(
+            client.get(request).await,
+            Err(Error::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_atk_range() {
+        let client = Client::new();
+        let request = RequestBuilder::new()
+            .with_type(CardType::NormalMonster)
+            .with_atk_range(1500, 2000)
+            .build();
+        let cards = client.get(request).await.unwrap();
+        assert!(!cards.is_empty());
+        assert!(cards.iter().all(|c| {
+            c.as_monster()
+                .and_then(|m| m.atk().value())
+                .is_some_and(|atk| (1500..=2000).contains(&atk))
+        }));
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_sorted_by_name() {
+        let client = Client::new();
+        let request = RequestBuilder::new()
+            .with_fname("Blue-Eyes")
+            .with_type(CardType::NormalMonster)
+            .with_sort(SortOrder::Name)
+            .build();
+        let result = client.get(request).await;
+        assert!(result.is_ok());
+        let names: Vec<_> = result
+            .unwrap()
+            .iter()
+            .map(|c| c.name().to_lowercase())
+            .collect();
+        assert!(names.len() > 1);
+        assert!(names.is_sorted());
+    }
+
+    #[tokio::test]
+    async fn sorted_results_keep_the_api_order() {
+        let monster = |id: u64, name: &str, atk: i32| {
+            let mut json = normal_monster_json(id, name);
+            json["atk"] = atk.into();
+            json
+        };
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("sort", "atk"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    monster(1, "Blue-Eyes White Dragon", 3000),
+                    monster(2, "Dark Magician", 2500),
+                    monster(3, "Alexandrite Dragon", 2000)
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("sort", "name"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    monster(3, "Alexandrite Dragon", 2000),
+                    monster(1, "Blue-Eyes White Dragon", 3000),
+                    monster(2, "Dark Magician", 2500)
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let sorted = |sort| {
+            RequestBuilder::new()
+                .with_race(MonsterRace::Dragon)
+                .with_sort(sort)
+                .build()
+        };
+
+        // highest ATK first
+        let cards = client.get(sorted(SortOrder::Atk)).await.unwrap();
+        let atks: Vec<_> = cards.iter().map(Card::by_atk).collect();
+        assert_eq!(atks, [Some(3000), Some(2500), Some(2000)]);
+        assert!(cards.is_sorted_by(|a, b| Card::by_atk_desc(a, b).is_le()));
+
+        // alphabetically by name
+        let cards = client.get(sorted(SortOrder::Name)).await.unwrap();
+        let names: Vec<_> = cards.iter().map(Card::by_name).collect();
+        assert!(names.is_sorted());
+        assert_eq!(cards[0].name(), "Alexandrite Dragon");
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_divine_monsters() {
+        let client = Client::new();
+        let request = RequestBuilder::new()
+            .with_attribute(Attribute::Divine)
+            .build();
+        let result = client.get(request).await;
+        assert!(result.is_ok());
+        let cards = result.unwrap();
+        assert!(cards.iter().all(|c| match c {
+            Card::Normal(m) => m.attribute == Attribute::Divine,
+            Card::Effect(m) => m.attribute == Attribute::Divine,
+            _ => false,
+        }));
+        assert!(cards.iter().any(|c| c.name() == "Obelisk the Tormentor"));
+    }
+
+    #[tokio::test]
+    #[ignore = "queries the live API"]
+    async fn get_field_spells() {
+        let client = Client::new();
+        let request = RequestBuilder::new().with_spell(SpellRace::Field).build();
+        let cards = client.get(request).await.unwrap();
+        assert!(cards.iter().all(|c| matches!(
+            c,
+            Card::Spell(s) if s.race == SpellRace::Field
+        )));
+        assert!(cards.iter().any(|c| c.name() == "Necrovalley"));
+    }
+
+    #[tokio::test]
+    async fn get_normal_monster() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Trent").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Normal(m) => {
+                assert_eq!(m.info.id, CardId(78780140));
+                assert_eq!(m.info.name, "Trent");
+                assert_eq!(
+                    m.info.desc,
+                    "A guardian of the woods, this massive tree is believed to be immortal."
+                );
+                assert_eq!(m.race, MonsterRace::Plant);
+                assert_eq!(m.attribute, Attribute::Earth);
+                assert_eq!(m.level, 5);
+                assert_eq!(m.atk, StatValue::Known(1500));
+                assert_eq!(m.def, StatValue::Known(1800));
+                assert_eq!(m.card_type, MonsterType::NormalMonster);
+                assert_eq!(m.info.human_readable_card_type, "Normal Monster");
+                assert_eq!(
+                    m.info.ygoprodeck_url,
+                    "https://ygoprodeck.com/card/trent-6617"
+                );
+            }
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_link_monster() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Apollousa, Bow of the Goddess").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Link(m) => {
+                assert_eq!(m.info.id, CardId(4280258));
+                assert_eq!(m.info.name, "Apollousa, Bow of the Goddess");
+                assert_eq!(
+                    m.info.desc,
+                    "2+ monsters with different names, except Tokens\r\nYou can only control 1 \"Apollousa, Bow of the Goddess\". The original ATK of this card becomes 800 x the number of Link Materials used for its Link Summon. Once per Chain, when your opponent activates a monster effect (Quick Effect): You can make this card lose exactly 800 ATK, and if you do, negate the activation."
+                );
+                assert_eq!(m.race, MonsterRace::Fairy);
+                assert_eq!(m.attribute, Attribute::Wind);
+                assert_eq!(m.atk, StatValue::Unknown);
+                assert_eq!(m.linkval, 4);
+                assert_eq!(m.card_type, MonsterType::LinkMonster);
+                assert_eq!(
+                    m.link_markers,
+                    vec![
+                        LinkMarker::Top,
+                        LinkMarker::BottomLeft,
+                        LinkMarker::Bottom,
+                        LinkMarker::BottomRight
+                    ]
+                );
+                assert_eq!(m.info.human_readable_card_type, "Link Effect Monster");
+                assert_eq!(
+                    m.info.ygoprodeck_url,
+                    "https://ygoprodeck.com/card/apollousa-bow-of-the-goddess-10242"
+                );
+            }
+            _ => panic!("Unexpected variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_effect_monster() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Man-eater Bug").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Effect(m) => {
+                assert_eq!(m.info.id, CardId(54652250));
+                assert_eq!(m.info.name, "Man-Eater Bug");
+                assert_eq!(
+                    m.info.desc,
+                    "FLIP: Target 1 monster on the field; destroy it."
+                );
+                assert_eq!(m.race, MonsterRace::Insect);
+                assert_eq!(m.attribute, Attribute::Earth);
+                assert_eq!(m.level, 2);
+                assert_eq!(m.atk, StatValue::Known(450));
+                assert_eq!(m.def, StatValue::Known(600));
+                assert_eq!(m.card_type, MonsterType::FlipEffectMonster);
+                assert_eq!(m.info.human_readable_card_type, "Flip Effect Monster");
+                assert_eq!(
+                    m.info.ygoprodeck_url,
+                    "https://ygoprodeck.com/card/man-eater-bug-4659"
+                );
+            }
+            _ => panic!("Unexpected monster variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_normal_spell() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Pot of Greed").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Spell(s) => {
+                assert_eq!(s.info.id, CardId(55144522));
+                assert_eq!(s.info.name, "Pot of Greed");
+                assert_eq!(s.info.desc, "Draw 2 cards.");
+                assert_eq!(s.race, SpellRace::Normal);
+                assert_eq!(s.info.human_readable_card_type, "Normal Spell");
+                assert_eq!(
+                    s.info.ygoprodeck_url,
+                    "https://ygoprodeck.com/card/pot-of-greed-4698"
+                );
+            }
+            _ => panic!("Unexpected monster variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_normal_trap() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Reckless Greed").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Trap(t) => {
+                assert_eq!(t.info.id, CardId(37576645));
+                assert_eq!(t.info.name, "Reckless Greed");
+                assert_eq!(
+                    t.info.desc,
+                    "Draw 2 cards and skip your next 2 Draw Phases."
+                );
+                assert_eq!(t.race, TrapRace::Normal);
+                assert_eq!(t.info.human_readable_card_type, "Normal Trap");
+                assert_eq!(
+                    t.info.ygoprodeck_url,
+                    "https://ygoprodeck.com/card/reckless-greed-3180"
+                );
+            }
+            _ => panic!("Unexpected monster variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_field_spell() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Necrovalley").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Spell(s) => {
+                assert_eq!(s.info.id, CardId(47355498));
+                assert_eq!(s.info.name, "Necrovalley");
+                assert_eq!(s.race, SpellRace::Field);
+                assert_eq!(s.info.human_readable_card_type, "Field Spell");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_equip_spell() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Axe of Despair").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Spell(s) => {
+                assert_eq!(s.info.id, CardId(40619825));
+                assert_eq!(s.info.name, "Axe of Despair");
+                assert_eq!(s.race, SpellRace::Equip);
+                assert_eq!(s.info.human_readable_card_type, "Equip Spell");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_continuous_spell() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Burning Land").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Spell(s) => {
+                assert_eq!(s.info.id, CardId(24294108));
+                assert_eq!(s.info.name, "Burning Land");
+                assert_eq!(s.race, SpellRace::Continuous);
+                assert_eq!(s.info.human_readable_card_type, "Continuous Spell");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_quick_play_spell() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Mystical Space Typhoon").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Spell(s) => {
+                assert_eq!(s.info.id, CardId(5318639));
+                assert_eq!(s.info.name, "Mystical Space Typhoon");
+                assert_eq!(s.race, SpellRace::QuickPlay);
+                assert_eq!(s.info.human_readable_card_type, "Quick-Play Spell");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_ritual_spell() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Black Luster Ritual").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Spell(s) => {
+                assert_eq!(s.info.id, CardId(55761792));
+                assert_eq!(s.info.name, "Black Luster Ritual");
+                assert_eq!(s.race, SpellRace::Ritual);
+                assert_eq!(s.info.human_readable_card_type, "Ritual Spell");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_continuous_trap() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Call of the Haunted").await;
+        assert!(result.is_ok());
+        let card = result.unwrap();
+
+        match card {
+            Card::Trap(t) => {
+                assert_eq!(t.info.id, CardId(97077563));
+                assert_eq!(t.info.name, "Call of the Haunted");
+                assert_eq!(t.race, TrapRace::Continuous);
+                assert_eq!(t.info.human_readable_card_type, "Continuous Trap");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_counter_trap() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Solemn Judgment").await;
         assert!(result.is_ok());
         let card = result.unwrap();
 
-        match card {
-            Card::Trap(t) => {
-                assert_eq!(t.info.id, CardId(37576645));
-                assert_eq!(t.info.name, "Reckless Greed");
-                assert_eq!(
-                    t.info.desc,
-                    "Draw 2 cards and skip your next 2 Draw Phases."
-                );
-                assert_eq!(t.race, TrapRace::Normal);
-                assert_eq!(t.info.human_readable_card_type, "Normal Trap");
-                assert_eq!(
-                    t.info.ygoprodeck_url,
-                    "https://ygoprodeck.com/card/reckless-greed-3180"
-                );
-            }
-            _ => panic!("Unexpected monster variant"),
+        match card {
+            Card::Trap(t) => {
+                assert_eq!(t.info.id, CardId(41420027));
+                assert_eq!(t.info.name, "Solemn Judgment");
+                assert_eq!(t.race, TrapRace::Counter);
+                assert_eq!(t.info.human_readable_card_type, "Counter Trap");
+            }
+            _ => panic!("Unexpected card variant"),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_card_not_found() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri());
+        match client.get_by_name("Trnet").await {
+            Ok(_) => panic!("Expected error, but got card"),
+            Err(e) => assert!(matches!(e, Error::NotFound)),
+        }
+    }
+
+    #[tokio::test]
+    async fn with_base_url() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("name", "Trent"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trent_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(format!("{}/", server.uri()));
+        let card = client.get_by_name("Trent").await.unwrap();
+        assert!(matches!(card, Card::Normal(m) if m.info.id == CardId(78780140)));
+    }
+
+    #[tokio::test]
+    async fn not_found_from_error_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(not_found())
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Trnet").await;
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn api_error_keeps_message() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "Invalid attribute specified."
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let error = client.get(Request::default()).await.unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::Api { status: 400, message } if message == "Invalid attribute specified."
+        ));
+        assert_eq!(
+            error.to_string(),
+            "API error (400): Invalid attribute specified."
+        );
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(trent_json())
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri()).with_timeout(Duration::from_millis(50));
+        match client.get_by_name("Trent").await {
+            Err(Error::Network(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected a timeout, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "stream-parse")]
+    #[tokio::test]
+    async fn stream_parse() {
+        let server = MockServer::start().await;
+        let cards: Vec<_> = (1..=500)
+            .map(|id| normal_monster_json(id, "Monster"))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "Monster"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": cards })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "Truncated"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(r#"{"data": [{"id": 1,"#, "application/json"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let cards = client.search_prefix("Monster").await.unwrap();
+        assert_eq!(cards.len(), 500);
+        assert_eq!(cards[499].id(), CardId(500));
+
+        assert!(matches!(
+            client.search_prefix("Truncated").await,
+            Err(Error::Deserialization(e)) if e.is_eof()
+        ));
+    }
+
+    #[tokio::test]
+    async fn from_reqwest() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(header("x-shared-client", "yes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trent_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-shared-client", "yes".parse().unwrap());
+        let shared = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let client = Client::from_reqwest(shared).base_url(format!("{}/", server.uri()));
+        assert!(client.get_by_name("Trent").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn request_timeout_overrides_client() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(trent_json())
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri()).with_timeout(Duration::from_millis(50));
+        let request = RequestBuilder::new()
+            .with_name("Trent")
+            .with_timeout(Duration::from_secs(5))
+            .build();
+        assert!(client.get(request).await.is_ok());
+
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new()
+            .with_name("Trent")
+            .with_timeout(Duration::from_millis(50))
+            .build();
+        match client.get(request).await {
+            Err(Error::Network(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected a timeout, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_fuzzy_best() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "Blue-Eyes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    normal_monster_json(38517737, "Blue-Eyes Alternative White Dragon"),
+                    normal_monster_json(89631139, "Blue-Eyes White Dragon"),
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(not_found())
+            .with_priority(u8::MAX)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let card = client
+            .resolve_fuzzy_best("Blue-Eyes Whlte Dragcn", 0.8)
+            .await
+            .unwrap();
+        assert!(matches!(card, Some(Card::Normal(m)) if m.info.id == CardId(89631139)));
+
+        let card = client
+            .resolve_fuzzy_best("Blue-Eyes Whlte Dragcn", 0.95)
+            .await
+            .unwrap();
+        assert!(card.is_none());
+    }
+
+    #[tokio::test]
+    async fn resolve_fuzzy_best_tries_every_word() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "Greeds"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [normal_monster_json(1, "Greedsworn Knight of the Vault")]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "Pot"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [normal_monster_json(55144522, "Pot of Greed")]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let card = client
+            .resolve_fuzzy_best("Pot of Greeds", 0.8)
+            .await
+            .unwrap();
+        assert!(matches!(card, Some(c) if c.id() == CardId(55144522)));
+    }
+
+    #[test]
+    fn similarity_tolerates_ocr_noise() {
+        assert_eq!(similarity("Pot of Greed", "pot of greed"), 1.0);
+        assert!(similarity("Pot of Greed", "Pot 0f Greeed") > 0.8);
+        assert!(similarity("Pot of Greed", "Raigeki") < 0.3);
+    }
+
+    #[tokio::test]
+    async fn name_word_match() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "ra"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    normal_monster_json(10000010, "The Winged Dragon of Ra"),
+                    normal_monster_json(12580477, "Raigeki"),
+                    normal_monster_json(50237654, "Dark Magician Girl the Dragon Knight"),
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new().with_name_word_match("ra").build();
+        let cards = client.get(request).await.unwrap();
+        let ids: Vec<_> = cards.iter().map(|c| c.id().0).collect();
+        assert_eq!(ids, vec![10000010, 12580477]);
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trent_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri()).with_retries(2);
+        assert!(client.get_by_name("Trent").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn no_retries_by_default() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Trent").await;
+        assert!(matches!(result, Err(Error::Api { status: 503, .. })));
+    }
+
+    #[tokio::test]
+    async fn not_found_is_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(not_found())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri()).with_retries(3);
+        let result = client.get_by_name("Trnet").await;
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn get_by_names() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("name", "Trent|Blue-Eyes White Dragon|Trnet"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    normal_monster_json(89631139, "Blue-Eyes White Dragon"),
+                    trent_json()["data"][0],
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let cards = client
+            .get_by_names(&["Trent", "Blue-Eyes White Dragon", "Trnet"])
+            .await
+            .unwrap();
+        assert_eq!(cards.len(), 2);
+        assert!(client.get_by_names(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn date_window() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("startdate", "2002-03-08"))
+            .and(query_param("enddate", "2002-03-08"))
+            .and(query_param("dateregion", "tcg"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [normal_monster_json(89631139, "Blue-Eyes White Dragon")]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new()
+            .with_start_date("2002-03-08")
+            .with_end_date("2002-03-08")
+            .with_date_region(DateRegion::Tcg)
+            .build();
+        assert_eq!(client.get(request).await.unwrap().len(), 1);
+
+        let request = RequestBuilder::new().with_end_date("2002-13-01").build();
+        let result = client.get(request).await;
+        assert!(matches!(result, Err(Error::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn from_cached_json() {
+        let path = std::env::temp_dir().join(format!("trent-dump-{}.json", std::process::id()));
+        let mut dump = trent_json();
+        dump["data"]
+            .as_array_mut()
+            .unwrap()
+            .push(normal_monster_json(89631139, "Blue-Eyes White Dragon"));
+        std::fs::write(&path, dump.to_string()).unwrap();
+
+        let client = Client::from_cached_json(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let card = client.get_by_name("trent").await.unwrap();
+        assert!(matches!(card, Card::Normal(m) if m.info.id == CardId(78780140)));
+
+        let request = RequestBuilder::new()
+            .with_type(CardType::NormalMonster)
+            .with_attribute(Attribute::Light)
+            .with_atk(3000)
+            .with_level(8)
+            .build();
+        let cards = client.get(request).await.unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].name(), "Blue-Eyes White Dragon");
+
+        let request = RequestBuilder::new()
+            .with_fname("eyes")
+            .with_def(1800)
+            .build();
+        assert!(matches!(client.get(request).await, Err(Error::NotFound)));
+        assert_eq!(client.get(Request::default()).await.unwrap().len(), 2);
+
+        let result = Client::from_cached_json(&path);
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn newest_ocg_cards() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("dateregion", "ocg"))
+            .and(query_param("sort", "new"))
+            .and(query_param_is_missing("startdate"))
+            .and(query_param_is_missing("enddate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trent_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new()
+            .with_date_region(DateRegion::Ocg)
+            .with_sort(SortOrder::New)
+            .build();
+        assert!(client.get(request).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn get_card_sets() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardsets.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "set_name": "Legend of Blue Eyes White Dragon",
+                    "set_code": "LOB",
+                    "num_of_cards": 126,
+                    "tcg_date": "2002-03-08"
+                },
+                {
+                    "set_name": "OTS Tournament Pack 1",
+                    "set_code": "OP01",
+                    "num_of_cards": 20
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let sets = client.get_card_sets().await.unwrap();
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].code, "LOB");
+        assert_eq!(sets[0].num_of_cards, 126);
+        assert_eq!(sets[0].tcg_date.as_deref(), Some("2002-03-08"));
+        assert!(sets[1].tcg_date.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_set_card_info() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardsetsinfo.php"))
+            .and(query_param("setcode", "SDY-001"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 46986414,
+                "name": "Dark Magician",
+                "set_name": "Starter Deck: Yugi",
+                "set_code": "SDY-001",
+                "set_rarity": "Ultra Rare",
+                "set_price": "12.34"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardsetsinfo.php"))
+            .and(query_param("setcode", "XXX-000"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "Card set code not found."
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let info = client.get_set_card_info("SDY-001").await.unwrap();
+        assert_eq!(info.id, CardId(46986414));
+        assert_eq!(info.set_rarity, "Ultra Rare");
+
+        let result = client.get_set_card_info("XXX-000").await;
+        assert!(matches!(result, Err(Error::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn archetype_stream() {
+        let server = MockServer::start().await;
+        let page = |ids: std::ops::Range<u64>| {
+            let cards: Vec<_> = ids
+                .map(|id| normal_monster_json(id, &format!("Blue-Eyes {id}")))
+                .collect();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": cards }))
+        };
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("archetype", "Blue-Eyes"))
+            .and(query_param("num", "100"))
+            .and(query_param("offset", "0"))
+            .respond_with(page(0..100))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("archetype", "Blue-Eyes"))
+            .and(query_param("num", "100"))
+            .and(query_param("offset", "100"))
+            .respond_with(page(100..130))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let cards: Vec<_> = client.archetype_stream("Blue-Eyes").collect().await;
+        assert_eq!(cards.len(), 130);
+        assert!(cards.iter().all(|c| c.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn get_archetypes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/archetypes.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "archetype_name": "Blackwing" },
+                { "archetype_name": "Blue-Eyes" },
+                { "archetype_name": "Dark Magician" }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let archetypes = client.get_archetypes().await.unwrap();
+        assert_eq!(archetypes.len(), 3);
+        assert!(archetypes.iter().any(|a| a == "Blue-Eyes"));
+    }
+
+    #[tokio::test]
+    async fn check_db_version() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/checkDBVer.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "database_version": "131.42", "last_update": "2025-11-03 10:12:23" }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let version = client.check_db_version().await.unwrap();
+        assert_eq!(version.version, "131.42");
+        assert_eq!(version.last_update, "2025-11-03 10:12:23");
+    }
+
+    #[tokio::test]
+    async fn error_shaped_success_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("name", "Trnet"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "error": NOT_FOUND })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("level", "99"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "error": "Level must be a number between 0 and 13."
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let result = client.get_by_name("Trnet").await;
+        assert!(matches!(result, Err(Error::NotFound)));
+
+        let request = RequestBuilder::new().with_level(99).build();
+        let result = client.get(request).await;
+        assert!(matches!(result, Err(Error::Api { status: 200, .. })));
+    }
+
+    #[tokio::test]
+    async fn get_by_url() {
+        let server = MockServer::start().await;
+        let mut alternative = normal_monster_json(38517737, "Blue-Eyes Alternative White Dragon");
+        alternative["ygoprodeck_url"] =
+            "https://ygoprodeck.com/card/blue-eyes-alternative-white-dragon-9729".into();
+        let mut blue_eyes = normal_monster_json(89631139, "Blue-Eyes White Dragon");
+        blue_eyes["ygoprodeck_url"] =
+            "https://ygoprodeck.com/card/blue-eyes-white-dragon-7485".into();
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "dragon"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [alternative, blue_eyes]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let card = client
+            .get_by_url("https://ygoprodeck.com/card/blue-eyes-white-dragon-7485?tab=rulings")
+            .await
+            .unwrap();
+        assert_eq!(card.id(), CardId(89631139));
+
+        for url in [
+            "not a url",
+            "https://example.com/card/blue-eyes-white-dragon-7485",
+            "https://ygoprodeck.com/deck/blue-eyes-white-dragon-7485",
+        ] {
+            let result = client.get_by_url(url).await;
+            assert!(matches!(result, Err(Error::InvalidRequest(_))), "{url}");
         }
     }
 
     #[tokio::test]
-    async fn get_field_spell() {
-        let client = Client::new();
-        let result = client.get_by_name("Necrovalley").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    async fn first_printing() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardsets.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "set_name": "Starter Deck: Kaiba",
+                    "set_code": "SDK",
+                    "num_of_cards": 50,
+                    "tcg_date": "2002-03-29"
+                },
+                {
+                    "set_name": "Legend of Blue Eyes White Dragon",
+                    "set_code": "LOB",
+                    "num_of_cards": 126,
+                    "tcg_date": "2002-03-08"
+                },
+                {
+                    "set_name": "Promo Pack",
+                    "set_code": "PP",
+                    "num_of_cards": 1
+                }
+            ])))
+            .mount(&server)
+            .await;
 
-        match card {
-            Card::Spell(s) => {
-                assert_eq!(s.info.id, CardId(47355498));
-                assert_eq!(s.info.name, "Necrovalley");
-                assert_eq!(s.race, SpellRace::Field);
-                assert_eq!(s.info.human_readable_card_type, "Field Spell");
+        let printing = |name: &str, code: &str| {
+            serde_json::json!({
+                "set_name": name,
+                "set_code": code,
+                "set_rarity": "Ultra Rare",
+                "set_rarity_code": "(UR)",
+                "set_price": "0"
+            })
+        };
+        let mut blue_eyes = normal_monster_json(89631139, "Blue-Eyes White Dragon");
+        blue_eyes["card_sets"] = serde_json::json!([
+            printing("Promo Pack", "PP-EN001"),
+            printing("Starter Deck: Kaiba", "SDK-001"),
+            printing("Legend of Blue Eyes White Dragon", "LOB-001"),
+        ]);
+        let blue_eyes: Card = serde_json::from_value(blue_eyes).unwrap();
+
+        let client = Client::with_base_url(server.uri());
+        let first = client.first_printing(&blue_eyes).await.unwrap();
+        assert_eq!(first.map(|s| s.code), Some("LOB-001".to_string()));
+
+        let unprinted: Card = serde_json::from_value(normal_monster_json(1, "Unprinted")).unwrap();
+        assert!(client.first_printing(&unprinted).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn html_maintenance_page() {
+        let server = MockServer::start().await;
+        let page = "<html><body>Down for maintenance</body></html>";
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(page, "text/html"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardsets.php"))
+            .respond_with(ResponseTemplate::new(503).set_body_raw(page, "text/html"))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        match client.get_by_name("Trent").await {
+            Err(Error::Api { status, message }) => {
+                assert_eq!(status, 200);
+                assert_eq!(message, "non-JSON response");
             }
-            _ => panic!("Unexpected card variant"),
+            other => panic!("Expected an API error, got {other:?}"),
         }
+        assert!(matches!(
+            client.get_card_sets().await,
+            Err(Error::Api { status: 503, .. })
+        ));
     }
 
     #[tokio::test]
-    async fn get_equip_spell() {
+    async fn with_cache() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("name", "Trent"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trent_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri()).with_cache(8);
+        let first = client.get_by_name("Trent").await.unwrap();
+        let second = client.get_by_name("Trent").await.unwrap();
+        assert_eq!(first, second);
+
+        // the same query through `get` has the same URL
+        let request = RequestBuilder::new().with_name("Trent").build();
+        assert_eq!(client.get(request).await.unwrap(), vec![first]);
+    }
+
+    #[tokio::test]
+    async fn download_image() {
+        let server = MockServer::start().await;
+        let jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0];
+        Mock::given(method("GET"))
+            .and(path("/images/cards_small/78780140.jpg"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(jpeg.clone(), "image/jpeg"))
+            .mount(&server)
+            .await;
+
+        let image = CardImage {
+            id: 78780140,
+            url: format!("{}/images/cards/78780140.jpg", server.uri()),
+            url_small: format!("{}/images/cards_small/78780140.jpg", server.uri()),
+            url_cropped: format!("{}/images/cards_cropped/78780140.jpg", server.uri()),
+        };
         let client = Client::new();
-        let result = client.get_by_name("Axe of Despair").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+        let bytes = image.download_small(&client).await.unwrap();
+        assert!(!bytes.is_empty());
+        assert_eq!(bytes, jpeg);
 
-        match card {
-            Card::Spell(s) => {
-                assert_eq!(s.info.id, CardId(40619825));
-                assert_eq!(s.info.name, "Axe of Despair");
-                assert_eq!(s.race, SpellRace::Equip);
-                assert_eq!(s.info.human_readable_card_type, "Equip Spell");
-            }
-            _ => panic!("Unexpected card variant"),
+        assert!(matches!(
+            image.download(&client).await,
+            Err(Error::Network(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn download_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/images/cards/78780140.jpg"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(vec![0xFF, 0xD8, 0xFF, 0xE0], "image/jpeg")
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::new().with_timeout(Duration::from_millis(50));
+        match client
+            .download(&format!("{}/images/cards/78780140.jpg", server.uri()))
+            .await
+        {
+            Err(Error::Network(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected a timeout, got {other:?}"),
         }
     }
 
     #[tokio::test]
-    async fn get_continuous_spell() {
-        let client = Client::new();
-        let result = client.get_by_name("Burning Land").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    async fn with_rate_limit() {
+        let server = mock_api().await;
+        let client = Client::with_base_url(server.uri()).with_rate_limit(10);
+        let start = std::time::Instant::now();
 
-        match card {
-            Card::Spell(s) => {
-                assert_eq!(s.info.id, CardId(24294108));
-                assert_eq!(s.info.name, "Burning Land");
-                assert_eq!(s.race, SpellRace::Continuous);
-                assert_eq!(s.info.human_readable_card_type, "Continuous Spell");
-            }
-            _ => panic!("Unexpected card variant"),
+        // 10 requests fit in the initial burst, the last 3 wait 100ms each
+        let lookups = (0..13).map(|_| client.get_by_name("Trent"));
+        for result in futures::future::join_all(lookups).await {
+            assert!(result.is_ok());
         }
+
+        assert!(start.elapsed() >= Duration::from_millis(280));
     }
 
     #[tokio::test]
-    async fn get_quick_play_spell() {
-        let client = Client::new();
-        let result = client.get_by_name("Mystical Space Typhoon").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    async fn rate_limit_covers_downloads() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/images/cards/78780140.jpg"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(vec![0xFF, 0xD8], "image/jpeg"))
+            .expect(13)
+            .mount(&server)
+            .await;
 
-        match card {
-            Card::Spell(s) => {
-                assert_eq!(s.info.id, CardId(5318639));
-                assert_eq!(s.info.name, "Mystical Space Typhoon");
-                assert_eq!(s.race, SpellRace::QuickPlay);
-                assert_eq!(s.info.human_readable_card_type, "Quick-Play Spell");
-            }
-            _ => panic!("Unexpected card variant"),
+        let client = Client::new().with_rate_limit(10);
+        let url = format!("{}/images/cards/78780140.jpg", server.uri());
+        let start = std::time::Instant::now();
+
+        let downloads = (0..13).map(|_| client.download(&url));
+        for result in futures::future::join_all(downloads).await {
+            assert!(result.is_ok());
         }
+
+        assert!(start.elapsed() >= Duration::from_millis(280));
     }
 
     #[tokio::test]
-    async fn get_ritual_spell() {
-        let client = Client::new();
-        let result = client.get_by_name("Black Luster Ritual").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    async fn get_all_by_name() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("name", "Dark Magician"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    normal_monster_json(46986414, "Dark Magician"),
+                    normal_monster_json(36996508, "Dark Magician"),
+                ]
+            })))
+            .mount(&server)
+            .await;
 
-        match card {
-            Card::Spell(s) => {
-                assert_eq!(s.info.id, CardId(55761792));
-                assert_eq!(s.info.name, "Black Luster Ritual");
-                assert_eq!(s.race, SpellRace::Ritual);
-                assert_eq!(s.info.human_readable_card_type, "Ritual Spell");
+        let client = Client::with_base_url(server.uri());
+        let ids: Vec<_> = client
+            .get_all_by_name("Dark Magician")
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.id())
+            .collect();
+        assert_eq!(ids, vec![CardId(46986414), CardId(36996508)]);
+
+        let first = client.get_by_name("Dark Magician").await.unwrap();
+        assert_eq!(first.id(), CardId(46986414));
+    }
+
+    #[tokio::test]
+    async fn search() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "blue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    normal_monster_json(6, "Skyblue Dragon"),
+                    normal_monster_json(1, "Bluebird"),
+                    normal_monster_json(2, "Deep-Blue Warrior"),
+                    normal_monster_json(3, "Maiden with Eyes of Blue"),
+                    normal_monster_json(4, "Blue-Eyes White Dragon"),
+                    normal_monster_json(5, "Blue"),
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let names: Vec<_> = client
+            .search("blue")
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "Blue",
+                "Blue-Eyes White Dragon",
+                "Bluebird",
+                "Deep-Blue Warrior",
+                "Maiden with Eyes of Blue",
+                "Skyblue Dragon",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn search_prefix() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("fname", "Blue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    normal_monster_json(1, "Blue-Eyes Alternative White Dragon"),
+                    normal_monster_json(2, "Blue-Eyes White Dragon"),
+                    normal_monster_json(3, "bluebird"),
+                    normal_monster_json(4, "Deep-Blue Warrior"),
+                    normal_monster_json(5, "Maiden with Eyes of Blue"),
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let ids: Vec<_> = client
+            .search_prefix("Blue")
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.id().0)
+            .collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn deserialization_error_keeps_cause() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{ "frameType": "normal", "name": "Trent" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        match client.get_by_name("Trent").await {
+            Err(Error::Deserialization(e)) => {
+                assert!(e.to_string().contains("missing field"), "{e}")
             }
-            _ => panic!("Unexpected card variant"),
+            other => panic!("Expected a deserialization error, got {other:?}"),
         }
     }
 
     #[tokio::test]
-    async fn get_continuous_trap() {
-        let client = Client::new();
-        let result = client.get_by_name("Call of the Haunted").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    async fn resolve_deck() {
+        let server = MockServer::start().await;
+        let mut blue_eyes = normal_monster_json(89631139, "Blue-Eyes White Dragon");
+        blue_eyes["card_images"] = serde_json::json!([
+            { "id": 89631139, "image_url": "", "image_url_small": "", "image_url_cropped": "" },
+            { "id": 89631141, "image_url": "", "image_url_small": "", "image_url_cropped": "" }
+        ]);
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("id", "89631139,89631141,23995346,1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    blue_eyes,
+                    normal_monster_json(23995346, "Blue-Eyes Ultimate Dragon"),
+                ]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
 
-        match card {
-            Card::Trap(t) => {
-                assert_eq!(t.info.id, CardId(97077563));
-                assert_eq!(t.info.name, "Call of the Haunted");
-                assert_eq!(t.race, TrapRace::Continuous);
-                assert_eq!(t.info.human_readable_card_type, "Continuous Trap");
-            }
-            _ => panic!("Unexpected card variant"),
+        let client = Client::with_base_url(server.uri());
+        let ydk = crate::deck::parse_ydk(
+            "#main\n89631139\n89631139\n89631141\n#extra\n23995346\n!side\n1\n",
+        );
+        let deck = client.resolve_deck(&ydk).await.unwrap();
+        let ids = |cards: &[Card]| cards.iter().map(Card::id).collect::<Vec<_>>();
+
+        assert_eq!(ids(&deck.main), vec![CardId(89631139); 3]);
+        assert_eq!(ids(&deck.extra), vec![CardId(23995346)]);
+        assert!(deck.side.is_empty());
+        assert_eq!(deck.missing, vec![CardId(1)]);
+
+        let empty = client.resolve_deck(&Ydk::default()).await.unwrap();
+        assert_eq!(empty, ResolvedDeck::default());
+    }
+
+    #[tokio::test]
+    async fn get_any() {
+        let server = MockServer::start().await;
+        for (race, ids) in [
+            ("Dragon", [(1, "Dragon A"), (2, "Both")]),
+            ("Spellcaster", [(2, "Both"), (3, "Spellcaster A")]),
+        ] {
+            Mock::given(method("GET"))
+                .and(path("/cardinfo.php"))
+                .and(query_param("race", race))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": ids.map(|(id, name)| normal_monster_json(id, name))
+                })))
+                .mount(&server)
+                .await;
         }
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("race", "Fiend"))
+            .respond_with(not_found())
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let requests = [MonsterRace::Dragon, MonsterRace::Spellcaster]
+            .map(|race| RequestBuilder::new().with_race(race).build());
+        let ids: Vec<_> = client
+            .get_any(&requests)
+            .await
+            .unwrap()
+            .iter()
+            .map(Card::id)
+            .collect();
+        assert_eq!(ids, vec![CardId(1), CardId(2), CardId(3)]);
+
+        let missing = [RequestBuilder::new().with_race(MonsterRace::Fiend).build()];
+        assert!(matches!(
+            client.get_any(&missing).await,
+            Err(Error::NotFound)
+        ));
     }
 
     #[tokio::test]
-    async fn get_counter_trap() {
-        let client = Client::new();
-        let result = client.get_by_name("Solemn Judgment").await;
-        assert!(result.is_ok());
-        let card = result.unwrap();
+    async fn get_from_sets() {
+        let server = MockServer::start().await;
+        for (set, ids) in [
+            ("Metal Raiders", [(1, "Metal Raiders A"), (2, "Reprint")]),
+            ("Spell Ruler", [(2, "Reprint"), (3, "Spell Ruler A")]),
+        ] {
+            Mock::given(method("GET"))
+                .and(path("/cardinfo.php"))
+                .and(query_param("cardset", set))
+                .and(query_param("type", "Normal Monster"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": ids.map(|(id, name)| normal_monster_json(id, name))
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
 
-        match card {
-            Card::Trap(t) => {
-                assert_eq!(t.info.id, CardId(41420027));
-                assert_eq!(t.info.name, "Solemn Judgment");
-                assert_eq!(t.race, TrapRace::Counter);
-                assert_eq!(t.info.human_readable_card_type, "Counter Trap");
+        let client = Client::with_base_url(server.uri());
+        let request = RequestBuilder::new()
+            .with_type(CardType::NormalMonster)
+            .with_cardset("Legend of Blue Eyes White Dragon")
+            .build();
+        let ids: Vec<_> = client
+            .get_from_sets(request.clone(), &["Metal Raiders", "Spell Ruler"])
+            .await
+            .unwrap()
+            .iter()
+            .map(Card::id)
+            .collect();
+        assert_eq!(ids, vec![CardId(1), CardId(2), CardId(3)]);
+
+        assert!(matches!(
+            client.get_from_sets(request, &[]).await,
+            Err(Error::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_by_konami_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("konami_id", "4041"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [normal_monster_json(46986414, "Dark Magician")]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("konami_id", "1"))
+            .respond_with(not_found())
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let card = client.get_by_konami_id(4041).await.unwrap();
+        assert_eq!(card.id(), CardId(46986414));
+        assert!(matches!(
+            client.get_by_konami_id(1).await,
+            Err(Error::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn rate_limited() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "5")
+                    .set_body_raw("<html>Too Many Requests</html>", "text/html"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        match client.get_by_name("Trent").await {
+            Err(Error::RateLimited { retry_after }) => {
+                assert_eq!(retry_after, Some(Duration::from_secs(5)));
             }
-            _ => panic!("Unexpected card variant"),
+            other => panic!("expected a rate limit error, got {other:?}"),
         }
     }
 
     #[tokio::test]
-    async fn get_card_not_found() {
-        let client = Client::new();
-        match client.get_by_name("Trnet").await {
-            Ok(_) => panic!("Expected error, but got card"),
-            Err(e) => assert!(matches!(e, Error::NotFound)),
+    async fn stream() {
+        let server = MockServer::start().await;
+        let cards = |ids: std::ops::Range<u64>| {
+            let cards: Vec<_> = ids
+                .map(|id| normal_monster_json(id, &format!("Dragon {id}")))
+                .collect();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": cards }))
+        };
+        for (offset, ids) in [("0", 0..100), ("100", 100..200), ("200", 200..250)] {
+            Mock::given(method("GET"))
+                .and(path("/cardinfo.php"))
+                .and(query_param("race", "Dragon"))
+                .and(query_param("num", "100"))
+                .and(query_param("offset", offset))
+                .respond_with(cards(ids))
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("race", "Dragon"))
+            .and(query_param_is_missing("num"))
+            .respond_with(cards(0..250))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = || RequestBuilder::new().with_race(MonsterRace::Dragon).build();
+        let streamed: Vec<_> = client
+            .stream(request())
+            .map(|c| c.unwrap().id())
+            .collect()
+            .await;
+        let batched: Vec<_> = client
+            .get(request())
+            .await
+            .unwrap()
+            .iter()
+            .map(Card::id)
+            .collect();
+        assert_eq!(streamed.len(), 250);
+        assert_eq!(streamed, batched);
+    }
+
+    #[tokio::test]
+    async fn released_only() {
+        let server = MockServer::start().await;
+        let mut released = normal_monster_json(1, "Released");
+        released["card_sets"] = serde_json::json!([{
+            "set_name": "Legend of Blue Eyes White Dragon",
+            "set_code": "LOB-001",
+            "set_rarity": "Ultra Rare",
+            "set_rarity_code": "(UR)",
+            "set_price": "0"
+        }]);
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("race", "Dragon"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [normal_monster_json(2, "Unreleased"), released]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = |released_only| {
+            RequestBuilder::new()
+                .with_race(MonsterRace::Dragon)
+                .with_released_only(released_only)
+                .build()
+        };
+        assert_eq!(client.get(request(false)).await.unwrap().len(), 2);
+
+        let cards = client.get(request(true)).await.unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].id(), CardId(1));
+        assert!(cards[0].info().is_released());
+    }
+
+    #[tokio::test]
+    async fn get_lenient() {
+        let server = MockServer::start().await;
+        let mut broken = normal_monster_json(2, "Broken Monster");
+        broken["atk"] = "lots".into();
+        let mut unknown = normal_monster_json(3, "Future Monster");
+        unknown["frameType"] = "maximum".into();
+        let body = serde_json::json!({
+            "data": [normal_monster_json(1, "Known Monster"), broken, unknown]
+        });
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let request = || RequestBuilder::new().with_race(MonsterRace::Dragon).build();
+        assert!(matches!(
+            client.get(request()).await,
+            Err(Error::Deserialization(_))
+        ));
+
+        let (cards, skipped) = client.get_lenient(request()).await.unwrap();
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].id(), CardId(1));
+        assert!(matches!(cards[1], Card::Unknown(_)));
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].id, Some(CardId(2)));
+        assert_eq!(skipped[0].name.as_deref(), Some("Broken Monster"));
+        assert!(skipped[0].error.to_string().contains("invalid digit"));
+    }
+
+    #[tokio::test]
+    async fn get_sets_released_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cardsets.php"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "set_name": "Legend of Blue Eyes White Dragon",
+                    "set_code": "LOB",
+                    "num_of_cards": 126,
+                    "tcg_date": "2002-03-08"
+                },
+                {
+                    "set_name": "Quarter Century Bonanza",
+                    "set_code": "RA03",
+                    "num_of_cards": "150",
+                    "tcg_date": "2024-11-07"
+                },
+                {
+                    "set_name": "OTS Tournament Pack 1",
+                    "set_code": "OP01",
+                    "num_of_cards": 20
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(server.uri());
+        let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let sets = client.get_sets_released_after(date).await.unwrap();
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].code, "RA03");
+        assert_eq!(sets[0].num_of_cards, 150);
+
+        let launch = NaiveDate::from_ymd_opt(2002, 3, 8).unwrap();
+        let sets = client.get_sets_released_after(launch).await.unwrap();
+        assert_eq!(sets.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_all() {
+        let page = |ids: std::ops::Range<u64>| {
+            let cards: Vec<_> = ids
+                .map(|id| normal_monster_json(id, &format!("Card {id}")))
+                .collect();
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": cards }))
+        };
+        async fn mount_pages(server: &MockServer, pages: Vec<(&str, ResponseTemplate)>) {
+            for (offset, response) in pages {
+                Mock::given(method("GET"))
+                    .and(path("/cardinfo.php"))
+                    .and(query_param("num", "2"))
+                    .and(query_param("offset", offset))
+                    .respond_with(response)
+                    .expect(1)
+                    .mount(server)
+                    .await;
+            }
         }
+
+        let server = MockServer::start().await;
+        mount_pages(
+            &server,
+            vec![("0", page(0..2)), ("2", page(2..4)), ("4", page(4..5))],
+        )
+        .await;
+        let client = Client::with_base_url(server.uri());
+        let mut reported = Vec::new();
+        let cards = client.get_all(2, |n| reported.push(n)).await.unwrap();
+        assert_eq!(cards.len(), 5);
+        assert_eq!(reported, [2, 4, 5]);
+
+        // the last page is full, so the next one is an empty result
+        let server = MockServer::start().await;
+        let empty = not_found();
+        mount_pages(
+            &server,
+            vec![("0", page(0..2)), ("2", page(2..4)), ("4", empty)],
+        )
+        .await;
+        let client = Client::with_base_url(server.uri());
+        assert_eq!(client.get_all(2, |_| {}).await.unwrap().len(), 4);
+
+        assert!(matches!(
+            client.get_all(0, |_| {}).await,
+            Err(Error::InvalidRequest(_))
+        ));
     }
 }
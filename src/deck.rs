@@ -0,0 +1,198 @@
+//! Reading `.ydk` deck files, the format used by YGOPro-based simulators
+//! and most deck builders.
+
+use std::fmt::Write;
+
+use crate::card::{Card, CardId, Vendor};
+
+/// The card IDs listed in a `.ydk` file, in file order.
+///
+/// A card played in several copies is listed once per copy.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ydk {
+    pub main: Vec<CardId>,
+    pub extra: Vec<CardId>,
+    pub side: Vec<CardId>,
+}
+
+impl Ydk {
+    /// Returns every distinct ID of the deck, in the order they first appear.
+    pub(crate) fn unique_ids(&self) -> Vec<CardId> {
+        let mut ids: Vec<CardId> = Vec::new();
+
+        for id in self.main.iter().chain(&self.extra).chain(&self.side) {
+            if !ids.contains(id) {
+                ids.push(*id);
+            }
+        }
+
+        ids
+    }
+}
+
+/// A [`Ydk`] whose IDs were looked up, see
+/// [`Client::resolve_deck`](crate::client::Client::resolve_deck).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolvedDeck {
+    pub main: Vec<Card>,
+    pub extra: Vec<Card>,
+    pub side: Vec<Card>,
+    /// The IDs that didn't match any card, once per occurrence.
+    pub missing: Vec<CardId>,
+}
+
+impl ResolvedDeck {
+    /// Writes the deck as a `.ydk` file, one card ID per copy, in deck order.
+    ///
+    /// Cards are written with their main ID, so alternate artworks come back
+    /// as the original artwork. [`ResolvedDeck::missing`] IDs are dropped.
+    pub fn to_ydk(&self) -> String {
+        let mut out = String::from("#created by trent\n");
+
+        for (header, cards) in [
+            ("#main", &self.main),
+            ("#extra", &self.extra),
+            ("!side", &self.side),
+        ] {
+            let _ = writeln!(out, "{header}");
+            for card in cards {
+                let _ = writeln!(out, "{}", card.id());
+            }
+        }
+
+        out
+    }
+
+    /// Sums the price of every card of the deck at `vendor`, counting each
+    /// copy.
+    ///
+    /// Cards without a price at `vendor` are left out of the total.
+    pub fn total_price(&self, vendor: Vendor) -> f64 {
+        self.main
+            .iter()
+            .chain(&self.extra)
+            .chain(&self.side)
+            .filter_map(|card| card.info().prices.first()?.price(vendor))
+            .sum()
+    }
+}
+
+/// Parses the contents of a `.ydk` file.
+///
+/// IDs are sorted into sections by the `#main`, `#extra` and `!side` headers;
+/// IDs before the first header count as Main Deck. Other lines starting with
+/// `#` or `!` (such as `#created by ...`) are comments and, like blank lines
+/// and anything that isn't an ID, are skipped.
+pub fn parse_ydk(input: &str) -> Ydk {
+    let mut ydk = Ydk::default();
+    let mut section = &mut ydk.main;
+
+    for line in input.lines().map(str::trim) {
+        match line {
+            "#main" => section = &mut ydk.main,
+            "#extra" => section = &mut ydk.extra,
+            "!side" => section = &mut ydk.side,
+            _ => {
+                if let Ok(id) = line.parse() {
+                    section.push(id);
+                }
+            }
+        }
+    }
+
+    ydk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    #[test]
+    fn parse_ydk() {
+        let ydk = super::parse_ydk(
+            "#created by YGOPro\r\n\
+             #main\r\n\
+             55144522\r\n\
+             55144522\r\n\
+             \r\n\
+             54652250\r\n\
+             #extra\r\n\
+             4280258\r\n\
+             !side\r\n\
+             05318639\r\n",
+        );
+
+        assert_eq!(
+            ydk,
+            Ydk {
+                main: vec![CardId(55144522), CardId(55144522), CardId(54652250)],
+                extra: vec![CardId(4280258)],
+                side: vec![CardId(5318639)],
+            }
+        );
+        assert_eq!(
+            ydk.unique_ids(),
+            [55144522, 54652250, 4280258, 5318639].map(CardId)
+        );
+        assert_eq!(super::parse_ydk(""), Ydk::default());
+    }
+
+    #[test]
+    fn to_ydk_round_trip() {
+        let spell = |id: CardId| -> Card {
+            card(
+                "spell",
+                serde_json::json!({ "id": id.0, "type": "Spell Card", "race": "Normal" }),
+            )
+        };
+        let ydk = Ydk {
+            main: [55144522, 55144522, 54652250, 55144522]
+                .map(CardId)
+                .to_vec(),
+            extra: vec![CardId(4280258)],
+            side: Vec::new(),
+        };
+        let deck = ResolvedDeck {
+            main: ydk.main.iter().copied().map(spell).collect(),
+            extra: ydk.extra.iter().copied().map(spell).collect(),
+            side: Vec::new(),
+            missing: Vec::new(),
+        };
+
+        let written = deck.to_ydk();
+        assert!(written.starts_with("#created by trent\n#main\n55144522\n"));
+        assert!(written.ends_with("#extra\n4280258\n!side\n"));
+        assert_eq!(super::parse_ydk(&written), ydk);
+    }
+
+    #[test]
+    fn total_price() {
+        let spell = |tcgplayer: &str| -> Card {
+            card(
+                "spell",
+                serde_json::json!({
+                    "race": "Normal",
+                    "card_prices": [{
+                        "cardmarket_price": "0.10",
+                        "tcgplayer_price": tcgplayer,
+                        "ebay_price": "",
+                        "amazon_price": "0.00",
+                        "coolstuffinc_price": "0.25"
+                    }]
+                }),
+            )
+        };
+        let deck = ResolvedDeck {
+            main: vec![spell("1.50"), spell("1.50"), spell("")],
+            extra: vec![spell("10.00")],
+            side: vec![spell("0.25")],
+            missing: Vec::new(),
+        };
+
+        assert_eq!(deck.total_price(Vendor::TcgPlayer), 13.25);
+        assert_eq!(deck.total_price(Vendor::Ebay), 0.0);
+        assert_eq!(deck.total_price(Vendor::CoolStuffInc), 1.25);
+        assert_eq!(ResolvedDeck::default().total_price(Vendor::Amazon), 0.0);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::card::Card;
+
+/// A rough breakdown of a card's text into its effects, see
+/// [`Card::parse_effect`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectStructure {
+    /// The effects found in the text, in order.
+    pub segments: Vec<EffectSegment>,
+}
+
+/// One sentence of effect text, split around its `:` and `;` markers.
+///
+/// In the card text convention, what comes before a colon is the condition
+/// (or trigger) of the effect and what comes before a semicolon is its cost
+/// or target.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EffectSegment {
+    /// The text before the `:`, e.g. `"If this card is Normal Summoned"`.
+    pub condition: Option<String>,
+    /// The text between the `:` and the `;`, e.g. `"Discard 1 card"`.
+    pub cost: Option<String>,
+    /// What the effect does.
+    pub effect: String,
+    /// Whether the effect is optional ("You can").
+    pub optional: bool,
+    /// Whether the text mentions "once per turn".
+    pub once_per_turn: bool,
+    /// Whether the effect is a Quick Effect.
+    pub quick_effect: bool,
+}
+
+impl Card {
+    /// Splits the card's text into a rough structure of effects.
+    ///
+    /// This is a heuristic meant for search and classification, not a rules
+    /// parser: the text is cut into sentences, and each sentence is split on
+    /// its first `:` and first `;`. It doesn't know about quoted card names
+    /// containing those characters, effects spanning several sentences, or
+    /// the material line of Extra Deck monsters, which comes out as a
+    /// segment of its own.
+    pub fn parse_effect(&self) -> EffectStructure {
+        let segments = sentences(&self.info().desc)
+            .into_iter()
+            .map(parse_segment)
+            .collect();
+
+        EffectStructure { segments }
+    }
+}
+
+/// Splits `text` into sentences, on line breaks and on periods followed by
+/// whitespace.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+
+    for line in text.lines() {
+        let mut start = 0;
+        let mut chars = line.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let at_boundary = chars.peek().is_none_or(|(_, next)| next.is_whitespace());
+            if c == '.' && at_boundary {
+                sentences.push(&line[start..=i]);
+                start = i + 1;
+            }
+        }
+        sentences.push(&line[start..]);
+    }
+
+    sentences
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn parse_segment(sentence: &str) -> EffectSegment {
+    let lower = sentence.to_lowercase();
+    let (condition, rest) = match sentence.split_once(':') {
+        Some((condition, rest)) => (Some(condition.trim().to_string()), rest),
+        None => (None, sentence),
+    };
+    let (cost, effect) = match rest.split_once(';') {
+        Some((cost, effect)) => (Some(cost.trim().to_string()), effect),
+        None => (None, rest),
+    };
+
+    EffectSegment {
+        condition,
+        cost,
+        effect: effect.trim().to_string(),
+        optional: lower.contains("you can"),
+        once_per_turn: lower.contains("once per turn"),
+        quick_effect: lower.contains("(quick effect)"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    #[test]
+    fn parse_effect() {
+        let card = card(
+            "effect",
+            serde_json::json!({
+                "name": "Test Monster",
+                "type": "Effect Monster",
+                "humanReadableCardType": "Effect Monster",
+                "desc": "If this card is Normal Summoned: You can discard 1 card; draw 2 cards. Once per turn, when your opponent activates a card (Quick Effect): You can negate the activation.\r\nCannot be destroyed by battle.",
+                "race": "Spellcaster",
+                "attribute": "DARK",
+                "level": 4,
+                "atk": 1800,
+                "def": 1000
+            }),
+        );
+
+        let segments = card.parse_effect().segments;
+        assert_eq!(segments.len(), 3);
+
+        assert_eq!(
+            segments[0],
+            EffectSegment {
+                condition: Some("If this card is Normal Summoned".to_string()),
+                cost: Some("You can discard 1 card".to_string()),
+                effect: "draw 2 cards.".to_string(),
+                optional: true,
+                once_per_turn: false,
+                quick_effect: false,
+            }
+        );
+
+        assert!(segments[1].once_per_turn && segments[1].quick_effect);
+        assert_eq!(segments[1].cost, None);
+        assert_eq!(segments[1].effect, "You can negate the activation.");
+
+        assert_eq!(segments[2].condition, None);
+        assert_eq!(segments[2].effect, "Cannot be destroyed by battle.");
+        assert!(!segments[2].optional);
+    }
+}
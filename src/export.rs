@@ -0,0 +1,59 @@
+use serde::Serialize;
+
+use crate::card::Card;
+
+/// An entry of the JSON array accepted by deck-builder imports.
+#[derive(Serialize)]
+struct BuilderEntry<'a> {
+    id: u64,
+    name: &'a str,
+    quantity: u32,
+}
+
+/// Exports cards and their quantities as a JSON array of
+/// `{"id", "name", "quantity"}` objects, the import format shared by several
+/// deck-builder apps.
+///
+/// `id` is the card's numeric passcode and `name` its exact name.
+pub fn to_builder_json(cards: &[(Card, u32)]) -> String {
+    let entries: Vec<_> = cards
+        .iter()
+        .map(|(card, quantity)| BuilderEntry {
+            id: card.id().0,
+            name: card.name(),
+            quantity: *quantity,
+        })
+        .collect();
+
+    serde_json::to_string(&entries).expect("builder entries are always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    fn spell(id: u64, name: &str) -> Card {
+        card(
+            "spell",
+            serde_json::json!({
+                "id": id,
+                "name": name,
+                "race": "Normal",
+                "humanReadableCardType": "Normal Spell"
+            }),
+        )
+    }
+
+    #[test]
+    fn builder_json() {
+        let cards = vec![
+            (spell(55144522, "Pot of Greed"), 1),
+            (spell(12580477, "Raigeki"), 3),
+        ];
+        assert_eq!(
+            to_builder_json(&cards),
+            r#"[{"id":55144522,"name":"Pot of Greed","quantity":1},{"id":12580477,"name":"Raigeki","quantity":3}]"#
+        );
+    }
+}
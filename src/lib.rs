@@ -1,3 +1,17 @@
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cache;
 pub mod card;
 pub mod client;
+pub mod deck;
+pub mod effect;
+pub mod export;
+pub mod list;
+pub mod locale;
+mod rate_limit;
+pub mod render;
 pub mod request;
+pub mod set;
+
+#[cfg(test)]
+mod testing;
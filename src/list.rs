@@ -0,0 +1,164 @@
+//! Chainable client-side filters over a list of cards, for narrowing down
+//! results after they have been fetched.
+
+use crate::card::{Attribute, Card, CardCategory};
+
+/// A list of cards, with combinators that each keep a subset of it, e.g.
+/// `list.monsters().with_attribute(Attribute::Dark).min_atk(2000)`.
+///
+/// Collect any iterator of cards into one, or convert a `Vec<Card>` such as
+/// the result of [`Client::get`](crate::client::Client::get).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CardList(pub Vec<Card>);
+
+impl CardList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Card> {
+        self.0.iter()
+    }
+
+    /// Keeps the cards for which `predicate` returns `true`.
+    pub fn filter(mut self, predicate: impl FnMut(&Card) -> bool) -> Self {
+        self.0.retain(predicate);
+        self
+    }
+
+    /// Keeps the monsters, from the Main or the Extra Deck. Tokens are not
+    /// kept.
+    pub fn monsters(self) -> Self {
+        self.filter(|card| card.category() == CardCategory::Monster)
+    }
+
+    /// Keeps the Spell Cards.
+    pub fn spells(self) -> Self {
+        self.filter(|card| card.category() == CardCategory::Spell)
+    }
+
+    /// Keeps the Trap Cards.
+    pub fn traps(self) -> Self {
+        self.filter(|card| card.category() == CardCategory::Trap)
+    }
+
+    /// Keeps the monsters and Tokens of `attribute`.
+    pub fn with_attribute(self, attribute: Attribute) -> Self {
+        self.filter(|card| {
+            card.as_monster()
+                .is_some_and(|monster| *monster.attribute() == attribute)
+        })
+    }
+
+    /// Keeps the monsters and Tokens with at least `atk` ATK. A `?` ATK
+    /// doesn't count as any value.
+    pub fn min_atk(self, atk: i32) -> Self {
+        self.filter(|card| card.by_atk().is_some_and(|a| a >= atk))
+    }
+
+    pub fn into_vec(self) -> Vec<Card> {
+        self.0
+    }
+}
+
+impl From<Vec<Card>> for CardList {
+    fn from(cards: Vec<Card>) -> Self {
+        Self(cards)
+    }
+}
+
+impl From<CardList> for Vec<Card> {
+    fn from(list: CardList) -> Self {
+        list.0
+    }
+}
+
+impl FromIterator<Card> for CardList {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for CardList {
+    type Item = Card;
+    type IntoIter = std::vec::IntoIter<Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CardList {
+    type Item = &'a Card;
+    type IntoIter = std::slice::Iter<'a, Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    fn monster(name: &str, attribute: &str, atk: i32) -> Card {
+        card(
+            "effect",
+            serde_json::json!({
+                "name": name,
+                "type": "Effect Monster",
+                "race": "Fiend",
+                "attribute": attribute,
+                "level": 4,
+                "atk": atk,
+                "def": 0
+            }),
+        )
+    }
+
+    #[test]
+    fn chained_filters() {
+        let list: CardList = [
+            monster("Dark Strong", "DARK", 2400),
+            monster("Dark Weak", "DARK", 1000),
+            monster("Light Strong", "LIGHT", 2500),
+            monster("Dark Unknown", "DARK", -1),
+            card(
+                "spell",
+                serde_json::json!({ "name": "Dark Spell", "race": "Normal" }),
+            ),
+            card(
+                "trap",
+                serde_json::json!({ "name": "Dark Trap", "race": "Counter" }),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let names = |list: &CardList| list.iter().map(Card::name).collect::<Vec<_>>().join(",");
+        let beaters = list
+            .clone()
+            .monsters()
+            .with_attribute(Attribute::Dark)
+            .min_atk(1500);
+        assert_eq!(names(&beaters), "Dark Strong");
+
+        assert_eq!(list.clone().monsters().len(), 4);
+        assert_eq!(names(&list.clone().spells()), "Dark Spell");
+        assert_eq!(names(&list.clone().traps()), "Dark Trap");
+        assert!(list.clone().min_atk(3000).is_empty());
+
+        let cards: Vec<Card> = list.with_attribute(Attribute::Light).into();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards.into_iter().collect::<CardList>().len(), 1);
+    }
+}
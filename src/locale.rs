@@ -0,0 +1,177 @@
+//! Card metadata labels in the languages cards are printed in.
+//!
+//! The `Display` impls of [`Attribute`] and [`MonsterRace`] print the
+//! English names the API uses; [`Attribute::display_localized`] and
+//! [`MonsterRace::display_localized`] give the names printed on cards in
+//! other languages.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    card::{Attribute, MonsterRace},
+    request::Language,
+};
+
+/// A language to show card metadata in.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    French,
+    German,
+    Italian,
+    Portuguese,
+}
+
+impl Locale {
+    /// The column of the locale in the tables below.
+    fn index(self) -> usize {
+        match self {
+            Locale::English => 0,
+            Locale::French => 1,
+            Locale::German => 2,
+            Locale::Italian => 3,
+            Locale::Portuguese => 4,
+        }
+    }
+}
+
+/// The locale matching a language cards can be fetched in, see
+/// [`RequestBuilder::with_language`](crate::request::RequestBuilder::with_language).
+impl From<Language> for Locale {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::French => Locale::French,
+            Language::German => Locale::German,
+            Language::Italian => Locale::Italian,
+            Language::Portuguese => Locale::Portuguese,
+        }
+    }
+}
+
+impl Attribute {
+    /// Returns the attribute's name in `locale`, in capitals as printed on
+    /// cards. [`Locale::English`] gives the same text as `Display`.
+    pub fn display_localized(&self, locale: Locale) -> &'static str {
+        let names = match self {
+            Attribute::Light => ["LIGHT", "LUMIÈRE", "LICHT", "LUCE", "LUZ"],
+            Attribute::Dark => ["DARK", "TÉNÈBRES", "FINSTERNIS", "OSCURITÀ", "TREVAS"],
+            Attribute::Water => ["WATER", "EAU", "WASSER", "ACQUA", "ÁGUA"],
+            Attribute::Fire => ["FIRE", "FEU", "FEUER", "FUOCO", "FOGO"],
+            Attribute::Earth => ["EARTH", "TERRE", "ERDE", "TERRA", "TERRA"],
+            Attribute::Wind => ["WIND", "VENT", "WIND", "VENTO", "VENTO"],
+            Attribute::Divine => ["DIVINE", "DIVIN", "GÖTTLICH", "DIVINO", "DIVINO"],
+        };
+
+        names[locale.index()]
+    }
+}
+
+impl MonsterRace {
+    /// Returns the monster Type's name in `locale`. [`Locale::English`] gives
+    /// the same text as `Display`.
+    pub fn display_localized(&self, locale: Locale) -> &'static str {
+        let names = match self {
+            MonsterRace::Aqua => ["Aqua", "Aqua", "Aqua", "Acqua", "Aqua"],
+            MonsterRace::Beast => ["Beast", "Bête", "Ungeheuer", "Bestia", "Besta"],
+            MonsterRace::BeastWarrior => [
+                "Beast-Warrior",
+                "Bête-Guerrier",
+                "Ungeheuer-Krieger",
+                "Guerriero-Bestia",
+                "Besta-Guerreira",
+            ],
+            MonsterRace::CreatorGod => [
+                "Creator-God",
+                "Dieu Créateur",
+                "Schöpfergott",
+                "Divinità-Creatrice",
+                "Deus Criador",
+            ],
+            MonsterRace::Cyberse => ["Cyberse", "Cyberse", "Cyberse", "Cyberso", "Ciberso"],
+            MonsterRace::Dinosaur => [
+                "Dinosaur",
+                "Dinosaure",
+                "Dinosaurier",
+                "Dinosauro",
+                "Dinossauro",
+            ],
+            MonsterRace::DivineBeast => [
+                "Divine-Beast",
+                "Bête Divine",
+                "Göttliches Ungeheuer",
+                "Divinità-Bestia",
+                "Besta Divina",
+            ],
+            MonsterRace::Dragon => ["Dragon", "Dragon", "Drache", "Drago", "Dragão"],
+            MonsterRace::Fairy => ["Fairy", "Elfe", "Fee", "Fata", "Fada"],
+            MonsterRace::Fiend => ["Fiend", "Démon", "Unterweltler", "Demone", "Demônio"],
+            MonsterRace::Fish => ["Fish", "Poisson", "Fisch", "Pesce", "Peixe"],
+            MonsterRace::Illusion => ["Illusion", "Illusion", "Illusion", "Illusione", "Ilusão"],
+            MonsterRace::Insect => ["Insect", "Insecte", "Insekt", "Insetto", "Inseto"],
+            MonsterRace::Machine => ["Machine", "Machine", "Maschine", "Macchina", "Máquina"],
+            MonsterRace::Plant => ["Plant", "Plante", "Pflanze", "Pianta", "Planta"],
+            MonsterRace::Psychic => ["Psychic", "Psychique", "Psi", "Psichico", "Psíquico"],
+            MonsterRace::Pyro => ["Pyro", "Pyro", "Pyro", "Pyro", "Piro"],
+            MonsterRace::Reptile => ["Reptile", "Reptile", "Reptil", "Rettile", "Réptil"],
+            MonsterRace::Rock => ["Rock", "Rocher", "Fels", "Roccia", "Rocha"],
+            MonsterRace::SeaSerpent => [
+                "Sea Serpent",
+                "Serpent de Mer",
+                "Seeschlange",
+                "Serpente Marino",
+                "Serpente Marinha",
+            ],
+            MonsterRace::Spellcaster => ["Spellcaster", "Magicien", "Hexer", "Incantatore", "Mago"],
+            MonsterRace::Thunder => ["Thunder", "Tonnerre", "Donner", "Tuono", "Trovão"],
+            MonsterRace::Warrior => ["Warrior", "Guerrier", "Krieger", "Guerriero", "Guerreiro"],
+            MonsterRace::WingedBeast => [
+                "Winged Beast",
+                "Bête Ailée",
+                "Geflügeltes Ungeheuer",
+                "Bestia Alata",
+                "Besta Alada",
+            ],
+            MonsterRace::Wyrm => ["Wyrm", "Wyrm", "Wyrm", "Wyrm", "Wyrm"],
+            MonsterRace::Zombie => ["Zombie", "Zombie", "Zombie", "Zombie", "Zumbi"],
+        };
+
+        names[locale.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_localized() {
+        assert_eq!(
+            Attribute::Dark.display_localized(Locale::French),
+            "TÉNÈBRES"
+        );
+        assert_eq!(Attribute::Light.display_localized(Locale::German), "LICHT");
+        assert_eq!(
+            MonsterRace::Spellcaster.display_localized(Locale::Italian),
+            "Incantatore"
+        );
+        assert_eq!(
+            MonsterRace::WingedBeast.display_localized(Language::Portuguese.into()),
+            "Besta Alada"
+        );
+
+        for attribute in [Attribute::Light, Attribute::Water, Attribute::Divine] {
+            assert_eq!(
+                attribute.display_localized(Locale::default()),
+                attribute.to_string()
+            );
+        }
+        for race in [
+            MonsterRace::BeastWarrior,
+            MonsterRace::SeaSerpent,
+            MonsterRace::Zombie,
+        ] {
+            assert_eq!(race.display_localized(Locale::English), race.to_string());
+        }
+    }
+}
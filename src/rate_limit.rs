@@ -0,0 +1,56 @@
+use std::{sync::Mutex, time::Duration};
+
+use tokio::time::Instant;
+
+/// A token bucket holding up to `per_second` tokens, refilled continuously
+/// at `per_second` tokens per second.
+///
+/// Callers that find the bucket empty take a token ahead of time, leaving it
+/// in debt, and sleep until their token would have been refilled. Concurrent
+/// callers thus queue up one after another without holding a lock across
+/// their sleep.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(per_second: u32) -> Self {
+        let per_second = f64::from(per_second);
+
+        Self {
+            per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: per_second,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.bucket.lock().expect("rate limiter poisoned");
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+
+            bucket.tokens = (bucket.tokens + elapsed * self.per_second).min(self.per_second);
+            bucket.refilled_at = now;
+            bucket.tokens -= 1.0;
+
+            if bucket.tokens >= 0.0 {
+                return;
+            }
+            Duration::from_secs_f64(-bucket.tokens / self.per_second)
+        };
+
+        tokio::time::sleep(wait).await;
+    }
+}
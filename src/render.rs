@@ -0,0 +1,222 @@
+use std::fmt::Write;
+
+use crate::card::{Attribute, Card, LevelKind};
+
+const RESET: &str = "\x1b[0m";
+
+/// Controls how [`Card::render_text_with`] lays out a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// The maximum line width, in characters, the effect text is wrapped
+    /// to. `0` disables wrapping.
+    pub width: usize,
+    /// Whether to emit ANSI color codes for the card's frame and attribute.
+    pub color: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            color: false,
+        }
+    }
+}
+
+impl Card {
+    /// Renders the card as plain text, wrapped to 80 columns, see
+    /// [`Card::render_text_with`].
+    pub fn render_text(&self) -> String {
+        self.render_text_with(&RenderOptions::default())
+    }
+
+    /// Renders the card as human-readable text: its name and attribute, its
+    /// type, its stats for monsters, and its effect text wrapped to
+    /// `opts.width`.
+    pub fn render_text_with(&self, opts: &RenderOptions) -> String {
+        let info = self.info();
+        let paint = |text: &str, code: &str| {
+            if opts.color {
+                format!("\x1b[{code}m{text}{RESET}")
+            } else {
+                text.to_string()
+            }
+        };
+        let mut out = paint(&info.name, frame_color(self));
+
+        if let Some(attribute) = self.as_monster().map(|m| m.attribute()) {
+            let _ = write!(
+                out,
+                " [{}]",
+                paint(&attribute.to_string(), attribute_color(attribute))
+            );
+        }
+        out.push('\n');
+
+        match self.as_monster() {
+            Some(m) => {
+                let card_type = m
+                    .card_type()
+                    .map_or_else(|| "Token".to_string(), ToString::to_string);
+                let _ = writeln!(out, "[{}/{card_type}]", m.race());
+                let mut stats = match (m.link_rating(), self.level_or_rank()) {
+                    (Some(link), _) => format!("LINK-{link}"),
+                    (None, Some((LevelKind::Rank, rank))) => format!("Rank {rank}"),
+                    (None, Some((LevelKind::Level, level))) => format!("Level {level}"),
+                    (None, None) => String::new(),
+                };
+                if let Some(scale) = m.scale() {
+                    let _ = write!(stats, "  Scale {scale}");
+                }
+                let _ = write!(stats, "  ATK/{}", m.atk());
+                if let Some(def) = m.def() {
+                    let _ = write!(stats, " DEF/{}", def);
+                }
+                // Tokens have no Level to put before their stats
+                let _ = writeln!(out, "{}", stats.trim_start());
+            }
+            None => {
+                let _ = writeln!(out, "[{}]", info.human_readable_card_type);
+            }
+        }
+
+        for paragraph in info.desc.lines() {
+            out.push('\n');
+            out.push_str(&wrap(paragraph.trim_end(), opts.width));
+        }
+
+        out
+    }
+}
+
+/// Word-wraps `text` so that no line is longer than `width` characters,
+/// except for single words that don't fit on a line by themselves.
+fn wrap(text: &str, width: usize) -> String {
+    if width == 0 {
+        return text.to_string();
+    }
+
+    let mut out = String::new();
+    let mut line_len = 0;
+
+    for word in text.split_whitespace() {
+        let len = word.chars().count();
+        if line_len > 0 && line_len + 1 + len > width {
+            out.push('\n');
+            line_len = 0;
+        } else if line_len > 0 {
+            out.push(' ');
+            line_len += 1;
+        }
+        out.push_str(word);
+        line_len += len;
+    }
+
+    out
+}
+
+fn frame_color(card: &Card) -> &'static str {
+    match card {
+        Card::Normal(_) => "33",
+        Card::Effect(_) => "38;5;208",
+        Card::Ritual(_) => "34",
+        Card::Fusion(_) => "35",
+        Card::Synchro(_) => "97",
+        Card::Xyz(_) => "90",
+        Card::Link(_) => "36",
+        Card::Pendulum(_) => "92",
+        Card::Spell(_) => "32",
+        Card::Trap(_) => "95",
+        Card::Skill(_) => "94",
+        Card::Token(_) => "37",
+        Card::Unknown(_) => "39",
+    }
+}
+
+fn attribute_color(attribute: &Attribute) -> &'static str {
+    match attribute {
+        Attribute::Light => "93",
+        Attribute::Dark => "35",
+        Attribute::Water => "34",
+        Attribute::Fire => "31",
+        Attribute::Earth => "33",
+        Attribute::Wind => "32",
+        Attribute::Divine => "93",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    fn dark_magician() -> Card {
+        card(
+            "normal",
+            serde_json::json!({
+                "id": 46986414,
+                "name": "Dark Magician",
+                "type": "Normal Monster",
+                "humanReadableCardType": "Normal Monster",
+                "desc": "The ultimate wizard in terms of attack and defense.",
+                "race": "Spellcaster",
+                "attribute": "DARK",
+                "atk": 2500,
+                "def": 2100,
+                "level": 7
+            }),
+        )
+    }
+
+    #[test]
+    fn render_text() {
+        assert_eq!(
+            dark_magician().render_text(),
+            "Dark Magician [DARK]\n\
+             [Spellcaster/Normal Monster]\n\
+             Level 7  ATK/2500 DEF/2100\n\
+             \n\
+             The ultimate wizard in terms of attack and defense."
+        );
+    }
+
+    #[test]
+    fn render_token() {
+        let token = card(
+            "token",
+            serde_json::json!({
+                "name": "Sheep Token",
+                "type": "Token",
+                "race": "Beast",
+                "attribute": "EARTH",
+                "atk": 0,
+                "def": 0
+            }),
+        );
+
+        assert_eq!(
+            token.render_text(),
+            "Sheep Token [EARTH]\n[Beast/Token]\nATK/0 DEF/0\n"
+        );
+    }
+
+    #[test]
+    fn render_text_with() {
+        let opts = RenderOptions {
+            width: 20,
+            color: true,
+        };
+        let text = dark_magician().render_text_with(&opts);
+        let mut lines = text.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("\x1b[33mDark Magician\x1b[0m [\x1b[35mDARK\x1b[0m]")
+        );
+        let desc: Vec<_> = lines.skip(3).collect();
+        assert_eq!(
+            desc,
+            vec!["The ultimate wizard", "in terms of attack", "and defense."]
+        );
+    }
+}
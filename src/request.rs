@@ -1,27 +1,100 @@
-use std::fmt::{self, Display};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    str::FromStr,
+    time::Duration,
+};
 
-use serde::Serialize;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use urlencoding::encode;
 
-use crate::card::{Attribute, LinkMarker, MonsterRace};
+use crate::{
+    card::{
+        Attribute, Card, CardId, Format, LinkMarker, MonsterRace, ParseError, SpellRace, StatValue,
+        TrapRace,
+    },
+    client::Error,
+};
 
-#[derive(Debug, Default)]
+/// A `cardinfo.php` query, built with [`RequestBuilder`].
+///
+/// Requests can be serialized, e.g. to save a search and replay it later;
+/// fields missing from the serialized form are left unset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Request<'a> {
-    names: Vec<&'a str>,
-    fname: Option<&'a str>,
-    atk: Option<i32>,
-    def: Option<i32>,
-    level: Option<u8>,
+    names: Vec<Cow<'a, str>>,
+    ids: Vec<CardId>,
+    konami_id: Option<u32>,
+    fname: Option<Cow<'a, str>>,
+    atk: Option<NumericFilter<i32>>,
+    def: Option<NumericFilter<i32>>,
+    levels: Vec<NumericFilter<u8>>,
     card_types: Vec<CardType>,
     races: Vec<MonsterRace>,
+    spell_races: Vec<SpellRace>,
+    trap_races: Vec<TrapRace>,
     attributes: Vec<Attribute>,
     link: Option<u8>,
     link_markers: Vec<LinkMarker>,
     scale: Option<u8>,
-    cardset: Option<&'a str>,
+    cardset: Option<Cow<'a, str>>,
+    name_word_match: Option<Cow<'a, str>>,
+    misc: bool,
+    tcgplayer_data: bool,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    date_region: Option<DateRegion>,
+    sort: Option<SortOrder>,
+    archetype: Option<Cow<'a, str>>,
+    has_effect: Option<bool>,
+    staple: bool,
+    released_only: bool,
+    format: Option<Format>,
+    language: Option<Language>,
+    num: Option<u32>,
+    offset: Option<u32>,
+    timeout: Option<Duration>,
 }
 
 impl<'a> Request<'a> {
+    /// A request without any filter, matching the whole card database.
+    ///
+    /// This downloads every card, over 13,000 of them, so it should only be
+    /// used deliberately, e.g. to build a local copy of the database.
+    pub fn all_cards() -> Self {
+        Self::default()
+    }
+
+    /// Whether the request sets no filter at all and would therefore match
+    /// every card.
+    fn is_unfiltered(&self) -> bool {
+        self.names.is_empty()
+            && self.ids.is_empty()
+            && self.konami_id.is_none()
+            && self.fname.is_none()
+            && self.atk.is_none()
+            && self.def.is_none()
+            && self.levels.is_empty()
+            && self.card_types.is_empty()
+            && self.races.is_empty()
+            && self.spell_races.is_empty()
+            && self.trap_races.is_empty()
+            && self.attributes.is_empty()
+            && self.link.is_none()
+            && self.link_markers.is_empty()
+            && self.scale.is_none()
+            && self.cardset.is_none()
+            && self.name_word_match.is_none()
+            && self.start_date.is_none()
+            && self.end_date.is_none()
+            && self.archetype.is_none()
+            && self.has_effect.is_none()
+            && !self.staple
+            && self.format.is_none()
+    }
+
     pub fn to_url_params(&self) -> String {
         let mut params = Vec::new();
 
@@ -29,7 +102,16 @@ impl<'a> Request<'a> {
             params.push(format!("name={}", encode(&self.names.join("|"))));
         }
 
-        if let Some(fname) = self.fname {
+        if !self.ids.is_empty() {
+            let ids: Vec<_> = self.ids.iter().map(CardId::to_string).collect();
+            params.push(format!("id={}", ids.join(",")));
+        }
+
+        if let Some(konami_id) = self.konami_id {
+            params.push(format!("konami_id={}", konami_id));
+        }
+
+        if let Some(fname) = &self.fname {
             params.push(format!("fname={}", encode(fname)));
         }
 
@@ -41,8 +123,9 @@ impl<'a> Request<'a> {
             params.push(format!("def={}", def));
         }
 
-        if let Some(level) = self.level {
-            params.push(format!("level={}", level));
+        if !self.levels.is_empty() {
+            let levels: Vec<_> = self.levels.iter().map(NumericFilter::to_string).collect();
+            params.push(format!("level={}", levels.join(",")));
         }
 
         if !self.card_types.is_empty() {
@@ -55,11 +138,13 @@ impl<'a> Request<'a> {
             params.push(format!("type={}", encode(&joined)));
         }
 
-        if !self.races.is_empty() {
+        if self.has_race_filter() {
             let joined = self
                 .races
                 .iter()
                 .map(|r| format!("{}", r))
+                .chain(self.spell_races.iter().map(|r| format!("{}", r)))
+                .chain(self.trap_races.iter().map(|r| format!("{}", r)))
                 .collect::<Vec<_>>()
                 .join(",");
             params.push(format!("race={}", encode(&joined)));
@@ -93,18 +178,298 @@ impl<'a> Request<'a> {
             params.push(format!("scale={}", scale));
         }
 
-        if let Some(cardset) = self.cardset {
+        if let Some(cardset) = &self.cardset {
             params.push(format!("cardset={}", encode(cardset)));
         }
 
+        if self.misc {
+            params.push("misc=yes".to_string());
+        }
+
+        if self.tcgplayer_data {
+            params.push("tcgplayer_data=yes".to_string());
+        }
+
+        if let Some(start_date) = &self.start_date {
+            params.push(format!("startdate={}", encode(start_date)));
+        }
+
+        if let Some(end_date) = &self.end_date {
+            params.push(format!("enddate={}", encode(end_date)));
+        }
+
+        if let Some(date_region) = &self.date_region {
+            params.push(format!("dateregion={}", date_region));
+        }
+
+        if let Some(sort) = &self.sort {
+            params.push(format!("sort={}", sort));
+        }
+
+        if let Some(archetype) = &self.archetype {
+            params.push(format!("archetype={}", encode(archetype)));
+        }
+
+        if let Some(has_effect) = self.has_effect {
+            params.push(format!("has_effect={}", has_effect));
+        }
+
+        if self.staple {
+            params.push("staple=yes".to_string());
+        }
+
+        if let Some(format) = self.format {
+            let format = format.to_string().to_lowercase();
+            params.push(format!("format={}", encode(&format)));
+        }
+
+        if let Some(language) = &self.language {
+            params.push(format!("language={}", language));
+        }
+
+        if let Some(num) = self.num {
+            params.push(format!("num={}", num));
+        }
+
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+
         params.join("&")
     }
+
+    /// The timeout overriding the client's one for this request, if any.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Replaces the set the request is restricted to.
+    pub(crate) fn set_cardset(&mut self, cardset: &str) {
+        self.cardset = Some(Cow::Owned(cardset.to_string()));
+    }
+
+    /// Restricts the request to `num` results starting at `offset`.
+    pub(crate) fn set_page(&mut self, num: u32, offset: u32) {
+        self.num = Some(num);
+        self.offset = Some(offset);
+    }
+
+    /// Applies the `num`/`offset` window to cards matched in memory.
+    pub(crate) fn page<'c>(&self, cards: impl Iterator<Item = &'c Card>) -> Vec<Card> {
+        cards
+            .skip(self.offset.unwrap_or(0) as usize)
+            .take(self.num.map_or(usize::MAX, |n| n as usize))
+            .cloned()
+            .collect()
+    }
+
+    /// Rejects filters that the API would not understand, before sending them.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        for date in [&self.start_date, &self.end_date].into_iter().flatten() {
+            if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+                return Err(Error::InvalidRequest(format!(
+                    "invalid date `{date}`, expected YYYY-MM-DD"
+                )));
+            }
+        }
+
+        if let Some((stat, filter)) = self.inverted_range() {
+            return Err(Error::InvalidRequest(format!(
+                "invalid {stat} range `{filter}`, the minimum is greater than the maximum"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the first ATK or DEF range whose minimum is above its maximum,
+    /// with the name of its stat.
+    fn inverted_range(&self) -> Option<(&'static str, NumericFilter<i32>)> {
+        [("ATK", self.atk), ("DEF", self.def)]
+            .into_iter()
+            .find_map(|(stat, filter)| Some((stat, filter.filter(NumericFilter::is_inverted)?)))
+    }
+
+    /// Evaluates the filters the API would apply against a card, for queries
+    /// served from memory.
+    ///
+    /// Names are compared ignoring case. The release date filters, the
+    /// staple filter, the sort order, the language and `misc` are not
+    /// applied.
+    pub(crate) fn matches(&self, card: &Card) -> bool {
+        let info = card.info();
+        let name = info.name.to_lowercase();
+        let monster = card.as_monster();
+
+        if !self.names.is_empty() && !self.names.iter().any(|n| name == n.to_lowercase()) {
+            return false;
+        }
+
+        // alternate artworks are looked up by their own passcode too
+        if !self.ids.is_empty()
+            && !self
+                .ids
+                .iter()
+                .any(|id| info.id == *id || info.images.iter().any(|i| i.id == id.0))
+        {
+            return false;
+        }
+
+        // the Konami ID is only known for cards loaded with `misc`
+        if let Some(konami_id) = self.konami_id
+            && info.misc.as_ref().and_then(|m| m.konami_id) != Some(u64::from(konami_id))
+        {
+            return false;
+        }
+
+        if let Some(fname) = &self.fname
+            && !name.contains(&fname.to_lowercase())
+        {
+            return false;
+        }
+
+        if !self.card_types.is_empty() {
+            let type_name = card.type_name();
+
+            if !self.card_types.iter().any(|t| t.to_string() == type_name) {
+                return false;
+            }
+        }
+
+        if let Some(archetype) = &self.archetype
+            && !info
+                .archetype
+                .as_ref()
+                .is_some_and(|a| a.eq_ignore_ascii_case(archetype))
+        {
+            return false;
+        }
+
+        if let Some(cardset) = &self.cardset
+            && !info
+                .sets
+                .iter()
+                .any(|s| s.name.eq_ignore_ascii_case(cardset))
+        {
+            return false;
+        }
+
+        // races are alternatives across monsters, Spells and Traps
+        if self.has_race_filter() {
+            let race_matches = match card {
+                Card::Spell(s) => self.spell_races.contains(&s.race),
+                Card::Trap(t) => self.trap_races.contains(&t.race),
+                _ => monster.is_some_and(|m| self.races.contains(m.race())),
+            };
+
+            if !race_matches {
+                return false;
+            }
+        }
+
+        let has_monster_filter = self.atk.is_some()
+            || self.def.is_some()
+            || !self.levels.is_empty()
+            || !self.attributes.is_empty()
+            || self.link.is_some()
+            || !self.link_markers.is_empty()
+            || self.scale.is_some();
+
+        if let Some(has_effect) = self.has_effect {
+            // without `misc`, only the frame tells whether a card is vanilla
+            let card_has_effect = info
+                .misc
+                .as_ref()
+                .map_or(!matches!(card, Card::Normal(_)), |m| m.has_effect);
+
+            if card_has_effect != has_effect {
+                return false;
+            }
+        }
+
+        // without `misc` the formats are unknown, so the card is kept
+        if let Some(format) = self.format
+            && let Some(misc) = &info.misc
+            && !misc.formats.contains(&format.to_string())
+        {
+            return false;
+        }
+
+        let Some(monster) = monster else {
+            return !has_monster_filter;
+        };
+
+        self.atk
+            .is_none_or(|atk| monster.atk().value().is_some_and(|a| atk.accepts(a)))
+            && self.def.is_none_or(|def| {
+                monster
+                    .def()
+                    .and_then(StatValue::value)
+                    .is_some_and(|d| def.accepts(d))
+            })
+            // like the API, the `level` filter matches Ranks too
+            && (self.levels.is_empty()
+                || card
+                    .level_or_rank()
+                    .is_some_and(|(_, l)| self.levels.iter().any(|level| level.accepts(l))))
+            && (self.attributes.is_empty() || self.attributes.contains(monster.attribute()))
+            && self.link.is_none_or(|link| monster.link_rating() == Some(link))
+            && self
+                .link_markers
+                .iter()
+                .all(|m| monster.link_markers().contains(m))
+            && self.scale.is_none_or(|scale| monster.scale() == Some(scale))
+    }
+
+    fn has_race_filter(&self) -> bool {
+        !self.races.is_empty() || !self.spell_races.is_empty() || !self.trap_races.is_empty()
+    }
+
+    /// Checks the filters the API cannot apply itself against a fetched card.
+    pub(crate) fn post_filter(&self, card: &Card) -> bool {
+        self.name_word_match
+            .as_deref()
+            .is_none_or(|term| matches_word_start(card.name(), term))
+            && (!self.released_only || card.info().is_released())
+    }
 }
 
+/// Appends `item` unless it is already in `items`, so that adding a filter
+/// twice doesn't repeat it in the URL.
+fn push_unique<T: PartialEq>(items: &mut Vec<T>, item: T) {
+    if !items.contains(&item) {
+        items.push(item);
+    }
+}
+
+/// Whether `term` occurs in `name`, ignoring case, at the start of a word.
+fn matches_word_start(name: &str, term: &str) -> bool {
+    let name = name.to_lowercase();
+    let term = term.to_lowercase();
+
+    name.match_indices(&term).any(|(i, _)| {
+        name[..i]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric())
+    })
+}
+
+/// Builds a [`Request`] one filter at a time.
+///
+/// Builders can be cloned, so a base set of filters can be kept as a
+/// template and refined separately for each query.
+#[derive(Debug, Clone)]
 pub struct RequestBuilder<'a> {
     request: Request<'a>,
 }
 
+impl<'a> Default for RequestBuilder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> RequestBuilder<'a> {
     pub fn new() -> Self {
         Self {
@@ -116,43 +481,247 @@ impl<'a> RequestBuilder<'a> {
         self.request
     }
 
+    /// Builds the request, failing if no filter was set or if an ATK or DEF
+    /// range has its minimum above its maximum.
+    ///
+    /// An unfiltered request downloads the whole database, so this guards
+    /// against forgetting a filter; use [`Request::all_cards`] when that is
+    /// what you want. Options that don't filter, like sorting or `misc`, are
+    /// not enough. Requests made with [`RequestBuilder::build`] only have
+    /// their ranges checked when sent.
+    pub fn build_filtered(self) -> Result<Request<'a>, BuildError> {
+        if self.request.is_unfiltered() {
+            return Err(BuildError::NoFilter);
+        }
+
+        if let Some((stat, filter)) = self.request.inverted_range() {
+            return Err(BuildError::InvertedRange {
+                stat,
+                min: filter.value,
+                max: filter.max.unwrap_or(filter.value),
+            });
+        }
+
+        Ok(self.request)
+    }
+
+    /// Removes every filter and option set so far.
+    pub fn reset(self) -> Self {
+        Self::new()
+    }
+
+    /// Removes the names added with [`RequestBuilder::with_name`].
+    pub fn clear_names(mut self) -> Self {
+        self.request.names.clear();
+        self
+    }
+
+    /// Removes the IDs added with [`RequestBuilder::with_id`].
+    pub fn clear_ids(mut self) -> Self {
+        self.request.ids.clear();
+        self
+    }
+
+    /// Removes the levels set with [`RequestBuilder::with_level`] and its
+    /// variants.
+    pub fn clear_levels(mut self) -> Self {
+        self.request.levels.clear();
+        self
+    }
+
+    /// Removes the card types added with [`RequestBuilder::with_type`].
+    pub fn clear_types(mut self) -> Self {
+        self.request.card_types.clear();
+        self
+    }
+
+    /// Removes the monster, Spell and Trap races added so far.
+    pub fn clear_races(mut self) -> Self {
+        self.request.races.clear();
+        self.request.spell_races.clear();
+        self.request.trap_races.clear();
+        self
+    }
+
+    /// Removes the attributes added with [`RequestBuilder::with_attribute`].
+    pub fn clear_attributes(mut self) -> Self {
+        self.request.attributes.clear();
+        self
+    }
+
+    /// Removes the Link Arrows added with
+    /// [`RequestBuilder::with_link_marker`].
+    pub fn clear_link_markers(mut self) -> Self {
+        self.request.link_markers.clear();
+        self
+    }
+
+    /// Removes the set chosen with [`RequestBuilder::with_cardset`].
+    pub fn clear_cardset(mut self) -> Self {
+        self.request.cardset = None;
+        self
+    }
+
     pub fn with_name(mut self, name: &'a str) -> Self {
-        self.request.names.push(name);
+        push_unique(&mut self.request.names, Cow::Borrowed(name));
+        self
+    }
+
+    /// Adds a card ID to look up; like names, several IDs return every card
+    /// matching any of them.
+    pub fn with_id(mut self, id: CardId) -> Self {
+        push_unique(&mut self.request.ids, id);
+        self
+    }
+
+    /// Looks the card up by its ID in Konami's official database, which
+    /// differs from its passcode.
+    pub fn with_konami_id(mut self, konami_id: u32) -> Self {
+        self.request.konami_id = Some(konami_id);
         self
     }
 
     pub fn with_fname(mut self, fname: &'a str) -> Self {
-        self.request.fname = Some(fname);
+        self.request.fname = Some(Cow::Borrowed(fname));
+        self
+    }
+
+    /// Refines a fuzzy `fname` search to the cards where `term` starts a word
+    /// of the name, e.g. `"ra"` keeps "The Winged Dragon of Ra" and "Raigeki"
+    /// but drops "Dark Magician Girl the Dragon Knight".
+    ///
+    /// `term` is sent as `fname` unless one was already set, and the matching
+    /// is done on the returned cards, ignoring case.
+    pub fn with_name_word_match(mut self, term: &'a str) -> Self {
+        self.request.fname.get_or_insert(Cow::Borrowed(term));
+        self.request.name_word_match = Some(Cow::Borrowed(term));
         self
     }
 
     pub fn with_atk(mut self, atk: i32) -> Self {
-        self.request.atk = Some(atk);
+        self.request.atk = Some(NumericFilter::exact(atk));
+        self
+    }
+
+    /// Only returns monsters whose ATK compares to `atk` as `comparison`
+    /// says, e.g. `with_atk_cmp(Comparison::Gte, 2500)` for 2500 ATK or more.
+    pub fn with_atk_cmp(mut self, comparison: Comparison, atk: i32) -> Self {
+        self.request.atk = Some(NumericFilter::compared(comparison, atk));
+        self
+    }
+
+    /// Only returns monsters with an ATK between `min` and `max`, both
+    /// included.
+    ///
+    /// A `min` greater than `max` is rejected before the request is sent,
+    /// failing with [`Error::InvalidRequest`].
+    pub fn with_atk_range(mut self, min: i32, max: i32) -> Self {
+        self.request.atk = Some(NumericFilter::range(min, max));
         self
     }
 
     pub fn with_def(mut self, def: i32) -> Self {
-        self.request.def = Some(def);
+        self.request.def = Some(NumericFilter::exact(def));
+        self
+    }
+
+    /// Only returns monsters whose DEF compares to `def` as `comparison`
+    /// says.
+    pub fn with_def_cmp(mut self, comparison: Comparison, def: i32) -> Self {
+        self.request.def = Some(NumericFilter::compared(comparison, def));
+        self
+    }
+
+    /// Only returns monsters with a DEF between `min` and `max`, both
+    /// included, see [`RequestBuilder::with_atk_range`].
+    pub fn with_def_range(mut self, min: i32, max: i32) -> Self {
+        self.request.def = Some(NumericFilter::range(min, max));
         self
     }
 
-    pub fn with_level(mut self, level: u8) -> Self {
-        self.request.level = Some(level);
+    /// Adds a Level or Rank to look for; like races, several levels return
+    /// the monsters having any of them.
+    ///
+    /// This drops a comparison set with [`RequestBuilder::with_level_cmp`],
+    /// as the API doesn't combine it with other levels.
+    pub fn with_level(self, level: u8) -> Self {
+        self.with_levels(&[level])
+    }
+
+    /// Adds every Level or Rank in `levels`, see
+    /// [`RequestBuilder::with_level`].
+    pub fn with_levels(mut self, levels: &[u8]) -> Self {
+        self.request.levels.retain(|l| l.comparison.is_none());
+        for level in levels {
+            push_unique(&mut self.request.levels, NumericFilter::exact(*level));
+        }
+        self
+    }
+
+    /// Only returns monsters whose Level or Rank compares to `level` as
+    /// `comparison` says.
+    ///
+    /// This replaces any level set before, as the API doesn't combine a
+    /// comparison with other levels.
+    pub fn with_level_cmp(mut self, comparison: Comparison, level: u8) -> Self {
+        self.request.levels = vec![NumericFilter::compared(comparison, level)];
         self
     }
 
     pub fn with_type(mut self, card_type: CardType) -> Self {
-        self.request.card_types.push(card_type);
+        push_unique(&mut self.request.card_types, card_type);
         self
     }
 
+    /// Adds `race` to the accepted races. Races are alternatives: a monster
+    /// matches if it is any of them.
     pub fn with_race(mut self, race: MonsterRace) -> Self {
-        self.request.races.push(race);
+        push_unique(&mut self.request.races, race);
+        self
+    }
+
+    /// Adds all of `races` to the accepted races, see
+    /// [`RequestBuilder::with_race`].
+    pub fn with_races(mut self, races: &[MonsterRace]) -> Self {
+        for race in races {
+            push_unique(&mut self.request.races, race.clone());
+        }
+        self
+    }
+
+    /// Adds a Spell subtype to the accepted races, e.g.
+    /// [`SpellRace::QuickPlay`]. Like monster races, races are alternatives.
+    pub fn with_spell_race(mut self, race: SpellRace) -> Self {
+        push_unique(&mut self.request.spell_races, race);
+        self
+    }
+
+    /// Adds a Trap subtype to the accepted races, see
+    /// [`RequestBuilder::with_spell_race`].
+    pub fn with_trap_race(mut self, race: TrapRace) -> Self {
+        push_unique(&mut self.request.trap_races, race);
         self
     }
 
+    /// Only returns Spells of the subtype `race`, e.g. Field Spells: this
+    /// sets both `type=Spell Card` and the race.
+    ///
+    /// Unlike [`RequestBuilder::with_spell_race`] alone, this doesn't also
+    /// match Traps of the races they share with Spells (Normal, Continuous).
+    pub fn with_spell(mut self, race: SpellRace) -> Self {
+        push_unique(&mut self.request.card_types, CardType::Spell);
+        self.with_spell_race(race)
+    }
+
+    /// Only returns Traps of the subtype `race`, see
+    /// [`RequestBuilder::with_spell`].
+    pub fn with_trap(mut self, race: TrapRace) -> Self {
+        push_unique(&mut self.request.card_types, CardType::Trap);
+        self.with_trap_race(race)
+    }
+
     pub fn with_attribute(mut self, attribute: Attribute) -> Self {
-        self.request.attributes.push(attribute);
+        push_unique(&mut self.request.attributes, attribute);
         self
     }
 
@@ -162,7 +731,7 @@ impl<'a> RequestBuilder<'a> {
     }
 
     pub fn with_link_marker(mut self, link_marker: LinkMarker) -> Self {
-        self.request.link_markers.push(link_marker);
+        push_unique(&mut self.request.link_markers, link_marker);
         self
     }
 
@@ -171,13 +740,320 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Only returns cards printed in the set named `cardset`.
+    ///
+    /// The API filters on a single set, so calling this again replaces the
+    /// set; see [`Client::get_from_sets`](crate::client::Client::get_from_sets)
+    /// for cards from any of several sets.
     pub fn with_cardset(mut self, cardset: &'a str) -> Self {
-        self.request.cardset = Some(cardset);
+        self.request.cardset = Some(Cow::Borrowed(cardset));
+        self
+    }
+
+    /// Only returns cards released on or after `date`, either a
+    /// [`NaiveDate`] or a `YYYY-MM-DD` string.
+    ///
+    /// The format is checked before the request is sent, failing with
+    /// [`Error::InvalidRequest`].
+    pub fn with_start_date(mut self, date: impl Display) -> Self {
+        self.request.start_date = Some(date.to_string());
+        self
+    }
+
+    /// Only returns cards released on or before `date`, either a
+    /// [`NaiveDate`] or a `YYYY-MM-DD` string.
+    ///
+    /// The format is checked before the request is sent, failing with
+    /// [`Error::InvalidRequest`].
+    pub fn with_end_date(mut self, date: impl Display) -> Self {
+        self.request.end_date = Some(date.to_string());
+        self
+    }
+
+    /// Selects which region's release dates are used, both by the date
+    /// filters and by [`SortOrder::New`].
+    ///
+    /// It doesn't require any date, so combined with
+    /// `with_sort(SortOrder::New)` alone it returns the newest releases of
+    /// that region.
+    pub fn with_date_region(mut self, date_region: DateRegion) -> Self {
+        self.request.date_region = Some(date_region);
+        self
+    }
+
+    /// Has the API sort the results instead of returning them by ID.
+    pub fn with_sort(mut self, sort: SortOrder) -> Self {
+        self.request.sort = Some(sort);
+        self
+    }
+
+    /// Only returns the cards of the `archetype`, e.g. `"Blue-Eyes"`.
+    pub fn with_archetype(mut self, archetype: &'a str) -> Self {
+        self.request.archetype = Some(Cow::Borrowed(archetype));
+        self
+    }
+
+    /// Only returns cards with (`true`) or without (`false`) an effect,
+    /// across every frame type, e.g. to find vanilla monsters.
+    pub fn with_has_effect(mut self, has_effect: bool) -> Self {
+        self.request.has_effect = Some(has_effect);
+        self
+    }
+
+    /// Only returns the cards available in the play `format`, e.g.
+    /// [`Format::MasterDuel`].
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.request.format = Some(format);
+        self
+    }
+
+    /// Only returns the cards printed in at least one set, see
+    /// [`CardInfo::is_released`](crate::card::CardInfo::is_released).
+    ///
+    /// The API has no such filter, so the cards are filtered once fetched;
+    /// this alone doesn't keep [`RequestBuilder::build_filtered`] from
+    /// rejecting the request.
+    pub fn with_released_only(mut self, released_only: bool) -> Self {
+        self.request.released_only = released_only;
+        self
+    }
+
+    /// Only returns the cards YGOProDeck flags as staples, generically
+    /// useful cards played across many decks.
+    pub fn with_staple(mut self, staple: bool) -> Self {
+        self.request.staple = staple;
+        self
+    }
+
+    /// Has the API return card names and descriptions translated to
+    /// `language` instead of English.
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.request.language = Some(language);
+        self
+    }
+
+    /// Overrides the client's timeout for this request only, e.g. to give a
+    /// large download more time than the quick lookups the client is tuned
+    /// for.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.request.timeout = Some(timeout);
+        self
+    }
+
+    /// Asks the API to include extra metadata (views, formats, release
+    /// dates...) in [`CardInfo::misc`](crate::card::CardInfo::misc).
+    pub fn with_misc(mut self, misc: bool) -> Self {
+        self.request.misc = misc;
+        self
+    }
+
+    /// Has the API add each printing's TCGPlayer page and edition, see
+    /// [`CardSet::url`](crate::card::CardSet::url).
+    pub fn with_tcgplayer_data(mut self, tcgplayer_data: bool) -> Self {
+        self.request.tcgplayer_data = tcgplayer_data;
         self
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq)]
+/// Errors raised while building a [`Request`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// No filter was set, so the request would match every card.
+    NoFilter,
+    /// The `stat` range, `"ATK"` or `"DEF"`, has its minimum above its
+    /// maximum, so no card could match it.
+    InvertedRange {
+        stat: &'static str,
+        min: i32,
+        max: i32,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::NoFilter => {
+                write!(f, "The request has no filter and would match every card")
+            }
+            BuildError::InvertedRange { stat, min, max } => {
+                write!(
+                    f,
+                    "The {stat} range {min}-{max} has its minimum above its maximum"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// The region whose release dates are used by the date filters.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateRegion {
+    Tcg,
+    Ocg,
+}
+
+impl Display for DateRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateRegion::Tcg => write!(f, "tcg"),
+            DateRegion::Ocg => write!(f, "ocg"),
+        }
+    }
+}
+
+/// The languages the API can translate card text to, besides English.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Language {
+    #[serde(rename = "fr")]
+    French,
+    #[serde(rename = "de")]
+    German,
+    #[serde(rename = "it")]
+    Italian,
+    #[serde(rename = "pt")]
+    Portuguese,
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Language::French => write!(f, "fr"),
+            Language::German => write!(f, "de"),
+            Language::Italian => write!(f, "it"),
+            Language::Portuguese => write!(f, "pt"),
+        }
+    }
+}
+
+/// The operators the API accepts in front of the ATK, DEF and Level filters.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Comparison {
+    /// Strictly less than the value.
+    Lt,
+    /// Less than or equal to the value.
+    Lte,
+    /// Strictly greater than the value.
+    Gt,
+    /// Greater than or equal to the value.
+    Gte,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::Lt => write!(f, "lt"),
+            Comparison::Lte => write!(f, "lte"),
+            Comparison::Gt => write!(f, "gt"),
+            Comparison::Gte => write!(f, "gte"),
+        }
+    }
+}
+
+/// A numeric filter, sent as the value optionally prefixed by its
+/// comparison (e.g. `gte8`), or as an inclusive range (e.g. `1500,2000`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct NumericFilter<T> {
+    comparison: Option<Comparison>,
+    value: T,
+    /// The upper bound of a range, whose lower bound is `value`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max: Option<T>,
+}
+
+impl<T: PartialOrd + Copy> NumericFilter<T> {
+    fn exact(value: T) -> Self {
+        Self {
+            comparison: None,
+            value,
+            max: None,
+        }
+    }
+
+    fn compared(comparison: Comparison, value: T) -> Self {
+        Self {
+            comparison: Some(comparison),
+            value,
+            max: None,
+        }
+    }
+
+    fn range(min: T, max: T) -> Self {
+        Self {
+            comparison: None,
+            value: min,
+            max: Some(max),
+        }
+    }
+
+    /// Whether this is a range whose bounds are the wrong way around.
+    fn is_inverted(&self) -> bool {
+        self.max.is_some_and(|max| max < self.value)
+    }
+
+    fn accepts(&self, actual: T) -> bool {
+        if let Some(max) = self.max {
+            return self.value <= actual && actual <= max;
+        }
+
+        match self.comparison {
+            None => actual == self.value,
+            Some(Comparison::Lt) => actual < self.value,
+            Some(Comparison::Lte) => actual <= self.value,
+            Some(Comparison::Gt) => actual > self.value,
+            Some(Comparison::Gte) => actual >= self.value,
+        }
+    }
+}
+
+impl<T: Display> Display for NumericFilter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(comparison) = self.comparison {
+            write!(f, "{}", comparison)?;
+        }
+        write!(f, "{}", self.value)?;
+        if let Some(max) = &self.max {
+            write!(f, ",{}", max)?;
+        }
+        Ok(())
+    }
+}
+
+/// The orderings the API can sort results by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Highest ATK first.
+    Atk,
+    /// Highest DEF first.
+    Def,
+    /// Alphabetically by name.
+    Name,
+    /// Highest Level/Rank first.
+    Level,
+    /// Most recently released first.
+    New,
+    /// By passcode.
+    Id,
+}
+
+impl Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortOrder::Atk => write!(f, "atk"),
+            SortOrder::Def => write!(f, "def"),
+            SortOrder::Name => write!(f, "name"),
+            SortOrder::Level => write!(f, "level"),
+            SortOrder::New => write!(f, "new"),
+            SortOrder::Id => write!(f, "id"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CardType {
     #[serde(rename = "Effect Monster")]
     EffectMonster,
@@ -274,3 +1150,539 @@ impl Display for CardType {
         }
     }
 }
+
+impl FromStr for CardType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = match s {
+            "Effect Monster" => CardType::EffectMonster,
+            "Flip Effect Monster" => CardType::FlipEffectMonster,
+            "Flip Tuner Effect Monster" => CardType::FlipTunerEffectMonster,
+            "Gemini Monster" => CardType::GeminiMonster,
+            "Normal Monster" => CardType::NormalMonster,
+            "Normal Tuner Monster" => CardType::NormalTunerMonster,
+            "Pendulum Effect Monster" => CardType::PendulumEffectMonster,
+            "Pendulum Effect Ritual Monster" => CardType::PendulumEffectRitualMonster,
+            "Pendulum Flip Effect Monster" => CardType::PendulumFlipEffectMonster,
+            "Pendulum Normal Monster" => CardType::PendulumNormalMonster,
+            "Pendulum Tuner Effect Monster" => CardType::PendulumTunerEffectMonster,
+            "Ritual Effect Monster" => CardType::RitualEffectMonster,
+            "Ritual Monster" => CardType::RitualMonster,
+            "Spirit Monster" => CardType::SpiritMonster,
+            "Toon Monster" => CardType::ToonMonster,
+            "Tuner Monster" => CardType::TunerMonster,
+            "Union Effect Monster" => CardType::UnionEffectMonster,
+            "Fusion Monster" => CardType::FusionMonster,
+            "Link Monster" => CardType::LinkMonster,
+            "Pendulum Effect Fusion Monster" => CardType::PendulumEffectFusionMonster,
+            "Synchro Monster" => CardType::SynchroMonster,
+            "Synchro Pendulum Effect Monster" => CardType::SynchroPendulumEffectMonster,
+            "Synchro Tuner Monster" => CardType::SynchroTunerMonster,
+            "XYZ Monster" => CardType::XYZMonster,
+            "XYZ Pendulum Effect Monster" => CardType::XYZPendulumEffectMonster,
+            "Token" => CardType::Token,
+            "Spell Card" => CardType::Spell,
+            "Trap Card" => CardType::Trap,
+            "Skill Card" => CardType::Skill,
+            _ => return Err(ParseError::new("card type", s)),
+        };
+        Ok(value)
+    }
+}
+
+impl TryFrom<&str> for CardType {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::card;
+
+    fn monster(race: &str, level: u8, atk: i32, def: i32) -> Card {
+        card(
+            "normal",
+            serde_json::json!({
+                "name": "Test Monster",
+                "type": "Normal Monster",
+                "humanReadableCardType": "Normal Monster",
+                "race": race,
+                "attribute": "LIGHT",
+                "level": level,
+                "atk": atk,
+                "def": def
+            }),
+        )
+    }
+
+    #[test]
+    fn misc() {
+        let request = RequestBuilder::new()
+            .with_name("Trent")
+            .with_misc(true)
+            .build();
+        assert_eq!(request.to_url_params(), "name=Trent&misc=yes");
+
+        let request = RequestBuilder::new().with_misc(false).build();
+        assert_eq!(request.to_url_params(), "");
+    }
+
+    #[test]
+    fn date_range() {
+        let request = RequestBuilder::new()
+            .with_start_date(NaiveDate::from_ymd_opt(2002, 3, 1).unwrap())
+            .with_end_date("2002-03-31")
+            .with_date_region(DateRegion::Tcg)
+            .build();
+        assert!(request.validate().is_ok());
+        assert_eq!(
+            request.to_url_params(),
+            "startdate=2002-03-01&enddate=2002-03-31&dateregion=tcg"
+        );
+
+        let request = RequestBuilder::new().with_start_date("03/01/2002").build();
+        assert!(matches!(request.validate(), Err(Error::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn sort() {
+        let request = RequestBuilder::new()
+            .with_fname("Blue-Eyes")
+            .with_sort(SortOrder::Atk)
+            .build();
+        assert_eq!(request.to_url_params(), "fname=Blue-Eyes&sort=atk");
+    }
+
+    #[test]
+    fn divine_attribute() {
+        let request = RequestBuilder::new()
+            .with_attribute(Attribute::Divine)
+            .build();
+        assert_eq!(request.to_url_params(), "attribute=DIVINE");
+
+        let request = RequestBuilder::new()
+            .with_attribute(Attribute::Light)
+            .with_attribute(Attribute::Divine)
+            .with_attribute(Attribute::Dark)
+            .build();
+        assert_eq!(request.to_url_params(), "attribute=LIGHT%2CDIVINE%2CDARK");
+    }
+
+    #[test]
+    fn date_region_without_dates() {
+        let request = RequestBuilder::new()
+            .with_date_region(DateRegion::Ocg)
+            .with_sort(SortOrder::New)
+            .build();
+        assert!(request.validate().is_ok());
+        assert_eq!(request.to_url_params(), "dateregion=ocg&sort=new");
+    }
+
+    #[test]
+    fn build_filtered() {
+        let result = RequestBuilder::new()
+            .with_sort(SortOrder::Atk)
+            .with_misc(true)
+            .build_filtered();
+        assert_eq!(result.unwrap_err(), BuildError::NoFilter);
+
+        let result = RequestBuilder::new()
+            .with_attribute(Attribute::Dark)
+            .build_filtered();
+        assert_eq!(result.unwrap().to_url_params(), "attribute=DARK");
+
+        assert_eq!(Request::all_cards().to_url_params(), "");
+    }
+
+    #[test]
+    fn has_effect() {
+        let request = RequestBuilder::new()
+            .with_level(4)
+            .with_has_effect(false)
+            .build();
+        assert_eq!(request.to_url_params(), "level=4&has_effect=false");
+        assert!(
+            RequestBuilder::new()
+                .with_has_effect(true)
+                .build_filtered()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn staple() {
+        let request = RequestBuilder::new().with_staple(true).build();
+        assert_eq!(request.to_url_params(), "staple=yes");
+
+        let request = RequestBuilder::new().with_staple(false).build();
+        assert_eq!(request.to_url_params(), "");
+    }
+
+    #[test]
+    fn comparisons() {
+        let request = RequestBuilder::new()
+            .with_level_cmp(Comparison::Gte, 8)
+            .with_atk_cmp(Comparison::Gte, 2500)
+            .build();
+        assert_eq!(request.to_url_params(), "atk=gte2500&level=gte8");
+
+        let request = RequestBuilder::new()
+            .with_atk_cmp(Comparison::Lt, 1000)
+            .with_def_cmp(Comparison::Lte, 500)
+            .with_level_cmp(Comparison::Gt, 4)
+            .build();
+        assert_eq!(request.to_url_params(), "atk=lt1000&def=lte500&level=gt4");
+
+        let request = RequestBuilder::new().with_atk(1800).build();
+        assert_eq!(request.to_url_params(), "atk=1800");
+    }
+
+    #[test]
+    fn stat_ranges() {
+        let request = RequestBuilder::new()
+            .with_atk_range(1500, 2000)
+            .with_def_range(0, 1000)
+            .build();
+        assert!(request.validate().is_ok());
+        assert_eq!(request.to_url_params(), "atk=1500,2000&def=0,1000");
+
+        let in_range = |atk| request.matches(&monster("Warrior", 4, atk, 1000));
+        assert!(in_range(1500) && in_range(1800) && in_range(2000));
+        assert!(!in_range(1400) && !in_range(2100));
+
+        let inverted = RequestBuilder::new().with_def_range(2000, 1500).build();
+        assert!(matches!(inverted.validate(), Err(Error::InvalidRequest(_))));
+
+        let result = RequestBuilder::new()
+            .with_atk_range(1500, 2000)
+            .with_def_range(2000, 1500)
+            .build_filtered();
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::InvertedRange {
+                stat: "DEF",
+                min: 2000,
+                max: 1500
+            }
+        );
+        assert!(
+            RequestBuilder::new()
+                .with_atk_range(2000, 2000)
+                .build_filtered()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn levels_match_ranks() {
+        let xyz = card(
+            "xyz",
+            serde_json::json!({
+                "type": "XYZ Monster",
+                "race": "Warrior",
+                "attribute": "LIGHT",
+                "level": 4,
+                "atk": 2500,
+                "def": 1500
+            }),
+        );
+
+        assert!(RequestBuilder::new().with_level(4).build().matches(&xyz));
+        assert!(!RequestBuilder::new().with_level(3).build().matches(&xyz));
+        assert!(
+            RequestBuilder::new()
+                .with_level_cmp(Comparison::Gte, 4)
+                .build()
+                .matches(&monster("Dragon", 4, 0, 0))
+        );
+    }
+
+    #[test]
+    fn tokens_match_monster_filters() {
+        let token = card(
+            "token",
+            serde_json::json!({
+                "type": "Token",
+                "race": "Beast",
+                "attribute": "EARTH",
+                "atk": 0,
+                "def": 0
+            }),
+        );
+
+        let request = RequestBuilder::new()
+            .with_attribute(Attribute::Earth)
+            .with_atk(0)
+            .with_def_cmp(Comparison::Lte, 500)
+            .build();
+        assert!(request.matches(&token));
+        assert!(!RequestBuilder::new().with_atk(1000).build().matches(&token));
+        assert!(!RequestBuilder::new().with_level(1).build().matches(&token));
+    }
+
+    #[test]
+    fn comparisons_match_in_memory() {
+        let monster = monster("Dragon", 8, 2500, 2000);
+        let matches = |comparison, atk| {
+            RequestBuilder::new()
+                .with_atk_cmp(comparison, atk)
+                .build()
+                .matches(&monster)
+        };
+
+        assert!(matches(Comparison::Gte, 2500));
+        assert!(!matches(Comparison::Gt, 2500));
+        assert!(matches(Comparison::Lte, 2500));
+        assert!(!matches(Comparison::Lt, 2500));
+        assert!(matches(Comparison::Lt, 2501));
+        assert!(
+            RequestBuilder::new()
+                .with_level_cmp(Comparison::Gte, 8)
+                .with_def_cmp(Comparison::Lt, 2500)
+                .build()
+                .matches(&monster)
+        );
+    }
+
+    #[test]
+    fn language() {
+        let request = RequestBuilder::new()
+            .with_name("Pot of Greed")
+            .with_language(Language::French)
+            .build();
+        assert_eq!(request.to_url_params(), "name=Pot%20of%20Greed&language=fr");
+    }
+
+    #[test]
+    fn races() {
+        let request = RequestBuilder::new()
+            .with_races(&[MonsterRace::Dragon, MonsterRace::Spellcaster])
+            .build();
+        assert_eq!(request.to_url_params(), "race=Dragon%2CSpellcaster");
+
+        assert!(request.matches(&monster("Dragon", 4, 1000, 1000)));
+        assert!(request.matches(&monster("Spellcaster", 4, 1000, 1000)));
+        assert!(!request.matches(&monster("Warrior", 4, 1000, 1000)));
+
+        let request = RequestBuilder::new()
+            .with_race(MonsterRace::SeaSerpent)
+            .with_race(MonsterRace::BeastWarrior)
+            .build();
+        assert_eq!(
+            request.to_url_params(),
+            "race=Sea%20Serpent%2CBeast-Warrior"
+        );
+    }
+
+    #[test]
+    fn format() {
+        let request = RequestBuilder::new().with_format(Format::Goat).build();
+        assert_eq!(request.to_url_params(), "format=goat");
+
+        let request = RequestBuilder::new().with_format(Format::SpeedDuel).build();
+        assert_eq!(request.to_url_params(), "format=speed%20duel");
+        assert!(request.matches(&monster("Dragon", 4, 1000, 1000)));
+    }
+
+    #[test]
+    fn ids() {
+        let request = RequestBuilder::new()
+            .with_id(CardId(55144522))
+            .with_id(CardId(4280258))
+            .build();
+        assert_eq!(request.to_url_params(), "id=55144522,4280258");
+        assert!(!request.is_unfiltered());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let request = RequestBuilder::new()
+            .with_name("Dark Magician")
+            .with_name("Dark Magician Girl")
+            .with_id(CardId(46986414))
+            .with_name_word_match("magician")
+            .with_atk_cmp(Comparison::Gte, 2000)
+            .with_level(7)
+            .with_type(CardType::NormalMonster)
+            .with_race(MonsterRace::Spellcaster)
+            .with_attribute(Attribute::Dark)
+            .with_cardset("Legend of Blue Eyes White Dragon")
+            .with_start_date("2002-03-08")
+            .with_date_region(DateRegion::Tcg)
+            .with_sort(SortOrder::Atk)
+            .with_archetype("Dark Magician")
+            .with_format(Format::SpeedDuel)
+            .with_language(Language::French)
+            .with_misc(true)
+            .with_timeout(Duration::from_secs(5))
+            .build();
+
+        let json = serde_json::to_string(&request).unwrap();
+        let replayed: Request<'static> = serde_json::from_str(&json).unwrap();
+        assert_eq!(replayed.to_url_params(), request.to_url_params());
+        assert_eq!(replayed.timeout(), Some(Duration::from_secs(5)));
+
+        let partial: Request = serde_json::from_str(r#"{"fname": "Blue-Eyes"}"#).unwrap();
+        assert_eq!(partial.to_url_params(), "fname=Blue-Eyes");
+    }
+
+    #[test]
+    fn konami_id() {
+        let request = RequestBuilder::new().with_konami_id(4041).build();
+        assert_eq!(request.to_url_params(), "konami_id=4041");
+        assert!(!request.matches(&monster("Spellcaster", 7, 2500, 2100)));
+    }
+
+    #[test]
+    fn levels() {
+        let request = RequestBuilder::new()
+            .with_type(CardType::XYZMonster)
+            .with_levels(&[3, 4])
+            .with_level(5)
+            .build();
+        assert_eq!(request.to_url_params(), "level=3,4,5&type=XYZ%20Monster");
+
+        let request = RequestBuilder::new().with_levels(&[3, 4, 5]).build();
+        for level in [3, 4, 5] {
+            assert!(request.matches(&monster("Warrior", level, 0, 0)));
+        }
+        assert!(!request.matches(&monster("Warrior", 6, 0, 0)));
+
+        let request = RequestBuilder::new()
+            .with_levels(&[3, 4])
+            .with_level_cmp(Comparison::Gte, 8)
+            .build();
+        assert_eq!(request.to_url_params(), "level=gte8");
+
+        let request = RequestBuilder::new()
+            .with_level_cmp(Comparison::Gte, 8)
+            .with_level(5)
+            .build();
+        assert_eq!(request.to_url_params(), "level=5");
+        assert!(!request.matches(&monster("Warrior", 8, 0, 0)));
+    }
+
+    #[test]
+    fn spell_and_trap_races() {
+        let spell = |race: &str| -> Card {
+            card(
+                "spell",
+                serde_json::json!({
+                    "name": "Test Spell",
+                    "type": "Spell Card",
+                    "humanReadableCardType": "Spell",
+                    "race": race
+                }),
+            )
+        };
+
+        let request = RequestBuilder::new()
+            .with_spell_race(SpellRace::QuickPlay)
+            .build();
+        assert_eq!(request.to_url_params(), "race=Quick-Play");
+        assert!(request.matches(&spell("Quick-Play")));
+        assert!(!request.matches(&spell("Field")));
+        assert!(!request.matches(&monster("Dragon", 4, 1000, 1000)));
+
+        let request = RequestBuilder::new()
+            .with_trap_race(TrapRace::Counter)
+            .with_spell_race(SpellRace::QuickPlay)
+            .build();
+        assert_eq!(request.to_url_params(), "race=Quick-Play%2CCounter");
+    }
+
+    #[test]
+    fn parse_card_type() {
+        for card_type in [CardType::XYZMonster, CardType::Spell, CardType::Token] {
+            assert_eq!(card_type.to_string().parse(), Ok(card_type));
+        }
+        assert_eq!(
+            CardType::try_from("Pendulum Effect Fusion Monster"),
+            Ok(CardType::PendulumEffectFusionMonster)
+        );
+        assert!("Spell".parse::<CardType>().is_err());
+    }
+
+    #[test]
+    fn duplicate_filters() {
+        let request = RequestBuilder::new()
+            .with_type(CardType::EffectMonster)
+            .with_attribute(Attribute::Dark)
+            .with_type(CardType::EffectMonster)
+            .with_attribute(Attribute::Dark)
+            .with_attribute(Attribute::Light)
+            .with_races(&[MonsterRace::Fiend, MonsterRace::Fiend])
+            .with_levels(&[4, 4])
+            .with_level(4)
+            .with_name("Trent")
+            .with_name("Trent")
+            .build();
+
+        assert_eq!(
+            request.to_url_params(),
+            "name=Trent&level=4&type=Effect%20Monster&race=Fiend&attribute=DARK%2CLIGHT"
+        );
+    }
+
+    #[test]
+    fn clone_and_clear() {
+        let base = RequestBuilder::new()
+            .with_type(CardType::Spell)
+            .with_name("Pot of Greed");
+        let mut derived = base
+            .clone()
+            .clear_names()
+            .with_name("Card Destruction")
+            .with_spell_race(SpellRace::Normal);
+
+        assert_eq!(
+            base.clone().build().to_url_params(),
+            "name=Pot%20of%20Greed&type=Spell%20Card"
+        );
+        assert_eq!(
+            derived.clone().build().to_url_params(),
+            "name=Card%20Destruction&type=Spell%20Card&race=Normal"
+        );
+
+        derived = derived.clear_types().clear_races();
+        assert_eq!(derived.build().to_url_params(), "name=Card%20Destruction");
+        assert!(base.reset().build().is_unfiltered());
+
+        let cardset = RequestBuilder::new()
+            .with_cardset("Metal Raiders")
+            .clear_cardset();
+        assert!(cardset.build().is_unfiltered());
+    }
+
+    #[test]
+    fn spell_and_trap_types() {
+        let request = RequestBuilder::new().with_spell(SpellRace::Field).build();
+        assert_eq!(request.to_url_params(), "type=Spell%20Card&race=Field");
+
+        let request = RequestBuilder::new()
+            .with_spell(SpellRace::QuickPlay)
+            .with_spell(SpellRace::Field)
+            .build();
+        assert_eq!(
+            request.to_url_params(),
+            "type=Spell%20Card&race=Quick-Play%2CField"
+        );
+
+        let request = RequestBuilder::new().with_trap(TrapRace::Counter).build();
+        assert_eq!(request.to_url_params(), "type=Trap%20Card&race=Counter");
+    }
+
+    #[test]
+    fn tcgplayer_data() {
+        let request = RequestBuilder::new()
+            .with_name("Trent")
+            .with_tcgplayer_data(true)
+            .build();
+        assert_eq!(request.to_url_params(), "name=Trent&tcgplayer_data=yes");
+    }
+}
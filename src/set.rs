@@ -0,0 +1,60 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::card::CardId;
+
+/// A card set (booster pack, structure deck, tin...) as listed by
+/// `cardsets.php`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SetInfo {
+    #[serde(rename = "set_name")]
+    pub name: String,
+    #[serde(rename = "set_code")]
+    pub code: String,
+    /// How many cards the set contains.
+    #[serde(deserialize_with = "u32_from_number_or_string")]
+    pub num_of_cards: u32,
+    /// TCG release date, as `YYYY-MM-DD`, if the set was released there.
+    #[serde(default)]
+    pub tcg_date: Option<String>,
+}
+
+impl SetInfo {
+    /// Returns the TCG release date, or `None` if the set wasn't released
+    /// there or the API sent a malformed date.
+    pub fn release_date(&self) -> Option<NaiveDate> {
+        let date = self.tcg_date.as_deref()?;
+
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+    }
+}
+
+/// A single printing of a card, as returned by `cardsetsinfo.php`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SetCardInfo {
+    pub id: CardId,
+    /// The name of the card.
+    pub name: String,
+    pub set_name: String,
+    pub set_code: String,
+    pub set_rarity: String,
+    pub set_price: String,
+}
+
+// `num_of_cards` is usually a number, but a few sets send it as a string
+fn u32_from_number_or_string<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Number(u32),
+        Text(String),
+    }
+
+    match Raw::deserialize(deserializer)? {
+        Raw::Number(value) => Ok(value),
+        Raw::Text(text) => text.trim().parse().map_err(serde::de::Error::custom),
+    }
+}
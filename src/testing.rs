@@ -0,0 +1,166 @@
+//! An in-process stand-in for the YGOProDeck API, serving canned
+//! `cardinfo.php` responses so the client tests run without network access,
+//! and a factory for the cards the other modules test against.
+
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+use crate::card::Card;
+
+/// The `name=` lookups answered by [`mock_api`], and their response bodies.
+const CARDS_BY_NAME: &[(&str, &str)] = &[
+    (
+        "Trent",
+        include_str!("../tests/fixtures/cardinfo/trent.json"),
+    ),
+    (
+        "Apollousa, Bow of the Goddess",
+        include_str!("../tests/fixtures/cardinfo/apollousa.json"),
+    ),
+    (
+        "Man-eater Bug",
+        include_str!("../tests/fixtures/cardinfo/man_eater_bug.json"),
+    ),
+    (
+        "Pot of Greed",
+        include_str!("../tests/fixtures/cardinfo/pot_of_greed.json"),
+    ),
+    (
+        "Reckless Greed",
+        include_str!("../tests/fixtures/cardinfo/reckless_greed.json"),
+    ),
+    (
+        "Necrovalley",
+        include_str!("../tests/fixtures/cardinfo/necrovalley.json"),
+    ),
+    (
+        "Axe of Despair",
+        include_str!("../tests/fixtures/cardinfo/axe_of_despair.json"),
+    ),
+    (
+        "Burning Land",
+        include_str!("../tests/fixtures/cardinfo/burning_land.json"),
+    ),
+    (
+        "Mystical Space Typhoon",
+        include_str!("../tests/fixtures/cardinfo/mystical_space_typhoon.json"),
+    ),
+    (
+        "Black Luster Ritual",
+        include_str!("../tests/fixtures/cardinfo/black_luster_ritual.json"),
+    ),
+    (
+        "Call of the Haunted",
+        include_str!("../tests/fixtures/cardinfo/call_of_the_haunted.json"),
+    ),
+    (
+        "Solemn Judgment",
+        include_str!("../tests/fixtures/cardinfo/solemn_judgment.json"),
+    ),
+    (
+        "Sheep Token",
+        include_str!("../tests/fixtures/cardinfo/sheep_token.json"),
+    ),
+    (
+        "Destiny Draw",
+        include_str!("../tests/fixtures/cardinfo/destiny_draw.json"),
+    ),
+];
+
+/// Starts a mock API answering the lookups in [`CARDS_BY_NAME`], plus a
+/// couple of filtered searches. Any other `cardinfo.php` query is answered
+/// with the API's "no card matching" error.
+pub(crate) async fn mock_api() -> MockServer {
+    let server = MockServer::start().await;
+
+    for (name, body) in CARDS_BY_NAME {
+        Mock::given(method("GET"))
+            .and(path("/cardinfo.php"))
+            .and(query_param("name", *name))
+            .respond_with(json(body))
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/cardinfo.php"))
+        .and(query_param("type", "Link Monster"))
+        .and(query_param("attribute", "WIND"))
+        .and(query_param(
+            "linkmarker",
+            "Top,Bottom,Bottom-Right,Bottom-Left",
+        ))
+        .respond_with(json(include_str!(
+            "../tests/fixtures/cardinfo/apollousa.json"
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/cardinfo.php"))
+        .and(query_param("type", "Normal Monster"))
+        .and(query_param("attribute", "DARK"))
+        .and(query_param("atk", "1800"))
+        .respond_with(json(include_str!(
+            "../tests/fixtures/cardinfo/gil_garth.json"
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/cardinfo.php"))
+        .respond_with(not_found())
+        .with_priority(u8::MAX)
+        .mount(&server)
+        .await;
+
+    server
+}
+
+/// The error the API returns for a query that matches no card.
+pub(crate) const NOT_FOUND: &str = "No card matching your query was found in the database.";
+
+/// The API's response to a query that matches no card.
+pub(crate) fn not_found() -> ResponseTemplate {
+    ResponseTemplate::new(400).set_body_json(serde_json::json!({ "error": NOT_FOUND }))
+}
+
+fn json(body: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_raw(body, "application/json")
+}
+
+/// Builds a card payload of `frame_type` with the shared `CardInfo` fields
+/// filled in, followed by the variant-specific `fields`, which can also
+/// override the shared ones.
+pub(crate) fn card_json(frame_type: &str, fields: serde_json::Value) -> serde_json::Value {
+    let mut json = serde_json::json!({
+        "id": 1,
+        "name": "Test Card",
+        "desc": "",
+        "frameType": frame_type,
+        "humanReadableCardType": "",
+        "ygoprodeck_url": "",
+        "card_images": []
+    });
+    json.as_object_mut()
+        .unwrap()
+        .extend(fields.as_object().unwrap().clone());
+    json
+}
+
+/// Reads the card of [`card_json`].
+pub(crate) fn card(frame_type: &str, fields: serde_json::Value) -> Card {
+    serde_json::from_value(card_json(frame_type, fields)).unwrap()
+}
+
+/// Builds a `card_images` entry for the artwork `id`.
+pub(crate) fn card_image_json(id: u64) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "image_url": format!("https://images.ygoprodeck.com/images/cards/{id}.jpg"),
+        "image_url_small": "",
+        "image_url_cropped": ""
+    })
+}